@@ -0,0 +1,20 @@
+#![feature(test)]
+
+extern crate event_loop;
+extern crate test;
+extern crate window;
+
+use event_loop::{EventLoop, EventSettings, Events};
+use test::Bencher;
+use window::{NoWindow, WindowSettings};
+
+#[bench]
+fn bench_events_no_window(bencher: &mut Bencher) {
+    let mut window = NoWindow::new(&WindowSettings::new("bench", [100, 100]));
+    let mut events = Events::new(EventSettings::new().bench_mode(true));
+    bencher.iter(|| {
+        for _ in 0..1000 {
+            test::black_box(events.next(&mut window));
+        }
+    });
+}
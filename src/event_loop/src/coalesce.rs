@@ -0,0 +1,220 @@
+use std::collections::VecDeque;
+
+use input::{ControllerAxisEvent, Event, ResizeEvent, ScaleFactorChangedEvent};
+use window::Window;
+
+use crate::{next_input_batch, EventLoop, EventSettings, EventSource, Events};
+
+/// Merges input events that are ready at the same time.
+///
+/// Each rule is disabled by default:
+///
+/// - [`controller_axis()`](#method.controller_axis) keeps only the latest
+///   controller axis event per controller and axis.
+///   Analog sticks emit a stream of axis events,
+///   while an application usually only needs the latest position.
+/// - [`resize()`](#method.resize) keeps only the latest resize
+///   and scale factor changed events.
+///   When both are ready at the same time, e.g. when the window moves between monitors,
+///   the scale factor changed event is emitted directly before the resize event,
+///   so an application can update the scale factor and relayout once on resize.
+///
+/// Other events are emitted unchanged and in order.
+///
+/// This is created by [`EventSource::coalesce()`](trait.EventSource.html#method.coalesce).
+#[derive(Clone, Debug)]
+pub struct Coalesce<S = Events> {
+    events: S,
+    queue: VecDeque<Event>,
+    controller_axis: bool,
+    resize: bool,
+}
+
+impl<S> Coalesce<S>
+where
+    S: EventSource,
+{
+    /// Creates a new coalesce adapter.
+    pub fn new(events: S) -> Coalesce<S> {
+        Coalesce {
+            events,
+            queue: VecDeque::new(),
+            controller_axis: false,
+            resize: false,
+        }
+    }
+
+    /// Enable or disable keeping only the latest controller axis event
+    /// per controller and axis.
+    pub fn set_controller_axis(&mut self, enabled: bool) {
+        self.controller_axis = enabled;
+    }
+
+    /// Enable or disable keeping only the latest controller axis event
+    /// per controller and axis.
+    ///
+    /// This method moves the current adapter,
+    /// unlike [`set_controller_axis()`](#method.set_controller_axis),
+    /// so that it can be used in method chaining.
+    pub fn controller_axis(mut self, enabled: bool) -> Self {
+        self.set_controller_axis(enabled);
+        self
+    }
+
+    /// Enable or disable keeping only the latest resize and scale factor changed events,
+    /// with the scale factor changed event directly before the resize event.
+    pub fn set_resize(&mut self, enabled: bool) {
+        self.resize = enabled;
+    }
+
+    /// Enable or disable keeping only the latest resize and scale factor changed events,
+    /// with the scale factor changed event directly before the resize event.
+    ///
+    /// This method moves the current adapter,
+    /// unlike [`set_resize()`](#method.set_resize),
+    /// so that it can be used in method chaining.
+    pub fn resize(mut self, enabled: bool) -> Self {
+        self.set_resize(enabled);
+        self
+    }
+}
+
+impl<S> EventSource for Coalesce<S>
+where
+    S: EventSource,
+{
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        if let Some(e) = self.queue.pop_front() {
+            return Some(e);
+        }
+        let (mut batch, last) = next_input_batch(&mut self.events, window);
+        // Keep the last axis event per controller and axis,
+        // and the last resize and scale factor changed event.
+        let mut seen = vec![];
+        let mut seen_resize = false;
+        let mut seen_scale_factor = false;
+        batch.reverse();
+        batch.retain(|e| {
+            if let Some(args) = e.controller_axis_args().filter(|_| self.controller_axis) {
+                if seen.contains(&(args.id, args.axis)) {
+                    return false;
+                }
+                seen.push((args.id, args.axis));
+            } else if self.resize && e.resize_args().is_some() {
+                return !std::mem::replace(&mut seen_resize, true);
+            } else if self.resize && e.scale_factor_changed_args().is_some() {
+                return !std::mem::replace(&mut seen_scale_factor, true);
+            }
+            true
+        });
+        batch.reverse();
+        if self.resize {
+            // Move the scale factor changed event directly before the resize event.
+            let scale_factor = batch
+                .iter()
+                .position(|e| e.scale_factor_changed_args().is_some());
+            if let Some(i) = scale_factor {
+                if batch.iter().any(|e| e.resize_args().is_some()) {
+                    let e = batch.remove(i);
+                    let resize = batch
+                        .iter()
+                        .position(|e| e.resize_args().is_some())
+                        .unwrap();
+                    batch.insert(resize, e);
+                }
+            }
+        }
+        self.queue.extend(batch);
+        self.queue.extend(last);
+        self.queue.pop_front()
+    }
+
+    fn events(&self) -> &Events {
+        self.events.events()
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        self.events.events_mut()
+    }
+}
+
+impl<S> EventLoop for Coalesce<S>
+where
+    S: EventSource,
+{
+    fn get_event_settings(&self) -> EventSettings {
+        self.events.get_event_settings()
+    }
+    fn set_event_settings(&mut self, settings: EventSettings) {
+        self.events.set_event_settings(settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{ControllerAxisArgs, Input, Motion, ResizeArgs, ScaleFactorChangedArgs};
+
+    use super::*;
+    use crate::tests::TestWindow;
+
+    #[test]
+    fn test_coalesce_controller_axis() {
+        // Returns the axis and position of emitted controller axis events.
+        fn axes(controller_axis: bool) -> Vec<(u8, f64)> {
+            let mut window = TestWindow::new();
+            for &(axis, position) in &[(0, 0.1), (1, 0.5), (0, 0.2), (0, 0.3)] {
+                window.events.push_back(
+                    Motion::ControllerAxis(ControllerAxisArgs::new(0, axis, position)).into(),
+                );
+            }
+            let mut events = Events::new(EventSettings::new())
+                .coalesce()
+                .controller_axis(controller_axis);
+            (0..10)
+                .filter_map(|_| events.next(&mut window).unwrap().controller_axis_args())
+                .map(|args| (args.axis, args.position))
+                .collect()
+        }
+
+        assert_eq!(axes(true), vec![(1, 0.5), (0, 0.3)]);
+        // Without the rule, all axis events are emitted.
+        assert_eq!(axes(false).len(), 4);
+    }
+
+    #[test]
+    fn test_coalesce_resize_scale_factor() {
+        let mut window = TestWindow::new();
+        let resize = |width: f64| -> Event {
+            Input::Resize(ResizeArgs {
+                window_size: [width, 600.0],
+                draw_size: [2 * width as u32, 1200],
+            })
+            .into()
+        };
+        window.events.push_back(resize(400.0));
+        window.events.push_back(
+            Input::ScaleFactorChanged(ScaleFactorChangedArgs {
+                scale_factor: 2.0,
+                new_draw_size: [1600, 1200],
+            })
+            .into(),
+        );
+        window.events.push_back(Input::Text("a".into()).into());
+        window.events.push_back(resize(800.0));
+        let mut events = Events::new(EventSettings::new()).coalesce().resize(true);
+        let inputs: Vec<Event> = (0..10)
+            .map(|_| events.next(&mut window).unwrap())
+            .filter(|e| e.is_input())
+            .collect();
+        let resizes: Vec<_> = inputs.iter().filter_map(|e| e.resize_args()).collect();
+        assert_eq!(resizes.len(), 1);
+        assert_eq!(resizes[0].window_size, [800.0, 600.0]);
+        assert!(inputs[inputs.len() - 2]
+            .scale_factor_changed_args()
+            .is_some());
+        assert!(inputs[inputs.len() - 1].resize_args().is_some());
+    }
+}
@@ -0,0 +1,255 @@
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc,
+    },
+};
+
+use input::{event_id::EventId, Event, IdleArgs};
+use window::Window;
+
+use crate::{EventLoop, EventSettings, EventSource, Events, Wakeup};
+
+const REDRAW: u8 = 1;
+const WAKE: u8 = 2;
+
+/// A handle for requesting a redraw, waking up an event loop
+/// or sending custom events from other threads.
+///
+/// This is created by [`CustomEvents::create_proxy()`](struct.CustomEvents.html#method.create_proxy).
+#[derive(Clone, Debug)]
+pub struct EventLoopProxy {
+    flags: Arc<AtomicU8>,
+    sender: Sender<Event>,
+}
+
+impl EventLoopProxy {
+    /// Requests a lazy event loop to stop waiting for input and render.
+    ///
+    /// The event loop handles pending input and renders on its next poll.
+    /// This has no effect on event loops that are not lazy,
+    /// since they render regularly.
+    pub fn request_redraw(&self) {
+        self.flags.fetch_or(REDRAW, Ordering::AcqRel);
+    }
+
+    /// Wakes up a lazy event loop waiting for input.
+    ///
+    /// The event loop emits an idle event with zero `dt` on its next poll,
+    /// e.g. for handling results from a worker thread,
+    /// and then continues waiting without rendering.
+    /// This has no effect on event loops that are not lazy,
+    /// since they do not wait for input.
+    pub fn wake(&self) {
+        self.flags.fetch_or(WAKE, Ordering::AcqRel);
+    }
+
+    /// Sends a custom event with an event id and arguments,
+    /// e.g. when a background loader has finished.
+    ///
+    /// The event is emitted before the next event of the event loop,
+    /// and wakes up a lazy event loop waiting for input.
+    /// The arguments of a custom event are accessed with `GenericEvent::with_args`.
+    ///
+    /// Returns `false` if the event loop has been dropped.
+    pub fn send_event<T>(&self, id: EventId, args: T) -> bool
+    where
+        T: Any + Send + Sync,
+    {
+        self.sender
+            .send(Event::Custom(id, Arc::new(args), None))
+            .is_ok()
+    }
+}
+
+/// Adds custom events to the events of an event loop,
+/// e.g. when an asset is reloaded in an editor.
+///
+/// Custom events are emitted before the next event of the event loop.
+/// Events can be pushed from other threads using a [`sender()`](#method.sender)
+/// or a proxy from [`create_proxy()`](#method.create_proxy),
+/// which can also request a redraw or wake up a lazy event loop.
+/// While waiting for input, a lazy event loop checks for requests and custom events
+/// every few milliseconds instead of blocking until the next input event.
+///
+/// The arguments of a custom event are accessed with `GenericEvent::with_args`.
+///
+/// This is the only supported way to wake up an event loop from other threads.
+/// The custom events adapter wraps the event loop directly,
+/// since it handles requests while the event loop waits for input.
+/// Other adapters can wrap it.
+#[derive(Debug)]
+pub struct CustomEvents {
+    events: Events,
+    flags: Arc<AtomicU8>,
+    sender: Sender<Event>,
+    receiver: Receiver<Event>,
+}
+
+impl CustomEvents {
+    /// Creates a new custom events adapter.
+    pub fn new(events: Events) -> CustomEvents {
+        let (sender, receiver) = mpsc::channel();
+        CustomEvents {
+            events,
+            flags: Arc::new(AtomicU8::new(0)),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Pushes a custom event with an event id and arguments.
+    pub fn push<T>(&self, id: EventId, args: T)
+    where
+        T: Any + Send + Sync,
+    {
+        // The receiver is owned by `self`, so sending can not fail.
+        let _ = self.sender.send(Event::Custom(id, Arc::new(args), None));
+    }
+
+    /// Returns a sender for pushing events from other threads.
+    pub fn sender(&self) -> Sender<Event> {
+        self.sender.clone()
+    }
+
+    /// Returns a handle that can be sent to other threads.
+    pub fn create_proxy(&self) -> EventLoopProxy {
+        EventLoopProxy {
+            flags: self.flags.clone(),
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl EventSource for CustomEvents {
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        if let Ok(e) = self.receiver.try_recv() {
+            return Some(e);
+        }
+        let flags = &self.flags;
+        let receiver = &self.receiver;
+        // Requests are cleared only when handled,
+        // so requests arriving while the event loop is busy are not lost.
+        let mut hook = || {
+            if let Ok(e) = receiver.try_recv() {
+                return Wakeup::Emit(e);
+            }
+            if flags.fetch_and(!REDRAW, Ordering::AcqRel) & REDRAW != 0 {
+                return Wakeup::Redraw;
+            }
+            if flags.fetch_and(!WAKE, Ordering::AcqRel) & WAKE != 0 {
+                return Wakeup::Emit(IdleArgs { dt: 0.0 }.into());
+            }
+            Wakeup::Wait
+        };
+        self.events.next_with_hook(window, Some(&mut hook))
+    }
+
+    fn events(&self) -> &Events {
+        &self.events
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        &mut self.events
+    }
+}
+
+impl EventLoop for CustomEvents {
+    fn get_event_settings(&self) -> EventSettings {
+        self.events.get_event_settings()
+    }
+    fn set_event_settings(&mut self, settings: EventSettings) {
+        self.events.set_event_settings(settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use input::{GenericEvent, IdleEvent, RenderEvent};
+
+    use super::*;
+    use crate::tests::TestWindow;
+
+    const ASSET_RELOADED: EventId = EventId("test/asset_reloaded");
+
+    #[test]
+    fn test_custom_events() {
+        let mut window = TestWindow::new();
+        let mut events = CustomEvents::new(Events::new(EventSettings::new()));
+        events.push(ASSET_RELOADED, String::from("player.png"));
+
+        let e = (0..10)
+            .map(|_| events.next(&mut window).unwrap())
+            .find(|e| e.event_id() == ASSET_RELOADED)
+            .unwrap();
+        let path = e.with_args(|args| args.downcast_ref::<String>().cloned());
+        assert_eq!(path, Some(String::from("player.png")));
+    }
+
+    #[test]
+    fn test_proxy_wake_and_redraw() {
+        let mut window = TestWindow::new();
+        let mut events = CustomEvents::new(Events::new(EventSettings::new().lazy(true)));
+        // Render and after render.
+        for _ in 0..2 {
+            events.next(&mut window).unwrap();
+        }
+
+        let proxy = events.create_proxy();
+        thread::spawn(move || proxy.wake()).join().unwrap();
+        let e = events.next(&mut window).unwrap();
+        assert_eq!(e.idle_args().map(|args| args.dt), Some(0.0));
+
+        let proxy = events.create_proxy();
+        thread::spawn(move || proxy.request_redraw())
+            .join()
+            .unwrap();
+        let rendered = (0..3).any(|_| events.next(&mut window).unwrap().render_args().is_some());
+        assert!(rendered);
+    }
+
+    #[test]
+    fn test_proxy_send_event() {
+        let mut window = TestWindow::new();
+        let mut events = CustomEvents::new(Events::new(EventSettings::new().lazy(true)));
+        for _ in 0..2 {
+            events.next(&mut window).unwrap();
+        }
+
+        let proxy = events.create_proxy();
+        thread::spawn(
+            move || assert!(proxy.send_event(ASSET_RELOADED, String::from("player.png"))),
+        )
+        .join()
+        .unwrap();
+        let e = events.next(&mut window).unwrap();
+        assert_eq!(e.event_id(), ASSET_RELOADED);
+        let path = e.with_args(|args| args.downcast_ref::<String>().cloned());
+        assert_eq!(path.as_deref(), Some("player.png"));
+    }
+
+    #[test]
+    fn test_proxy_redraw_while_rendering() {
+        use input::AfterRenderEvent;
+
+        let mut window = TestWindow::new();
+        let mut events = CustomEvents::new(Events::new(EventSettings::new().lazy(true)));
+        assert!(events.next(&mut window).unwrap().render_args().is_some());
+
+        // The redraw is requested while the current frame is rendered.
+        events.create_proxy().request_redraw();
+        assert!(events
+            .next(&mut window)
+            .unwrap()
+            .after_render_args()
+            .is_some());
+        assert!(events.next(&mut window).unwrap().render_args().is_some());
+    }
+}
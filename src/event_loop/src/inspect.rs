@@ -0,0 +1,102 @@
+use std::fmt;
+
+use input::Event;
+use window::Window;
+
+use crate::{EventLoop, EventSettings, EventSource, Events};
+
+/// Calls a closure on each event before it is returned,
+/// e.g. to trace the event stream.
+///
+/// This is created by [`EventSource::inspect()`](trait.EventSource.html#method.inspect).
+pub struct Inspect<F, S = Events> {
+    events: S,
+    f: F,
+}
+
+impl<F, S> Inspect<F, S>
+where
+    F: FnMut(&Event),
+    S: EventSource,
+{
+    /// Creates a new inspect adapter.
+    pub fn new(events: S, f: F) -> Inspect<F, S> {
+        Inspect { events, f }
+    }
+}
+
+impl<F, S> fmt::Debug for Inspect<F, S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Inspect")
+            .field("events", &self.events)
+            .finish()
+    }
+}
+
+impl<F, S> EventSource for Inspect<F, S>
+where
+    F: FnMut(&Event),
+    S: EventSource,
+{
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        let e = self.events.next(window)?;
+        (self.f)(&e);
+        Some(e)
+    }
+
+    fn events(&self) -> &Events {
+        self.events.events()
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        self.events.events_mut()
+    }
+}
+
+impl<F, S> EventLoop for Inspect<F, S>
+where
+    S: EventSource,
+{
+    fn get_event_settings(&self) -> EventSettings {
+        self.events.get_event_settings()
+    }
+    fn set_event_settings(&mut self, settings: EventSettings) {
+        self.events.set_event_settings(settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{ButtonArgs, ButtonState, Input, Key};
+
+    use super::*;
+    use crate::tests::TestWindow;
+
+    #[test]
+    fn test_inspect() {
+        let press: Event = ButtonArgs::new(ButtonState::Press, Key::A.into()).into();
+        let text: Event = Input::Text("a".into()).into();
+        let mut window = TestWindow::new();
+        window.events.push_back(press.clone());
+        window.events.push_back(text.clone());
+
+        let mut seen = vec![];
+        let mut emitted = vec![];
+        let mut events = Events::new(EventSettings::new()).inspect(|e| seen.push(e.clone()));
+        for _ in 0..10 {
+            emitted.push(events.next(&mut window).unwrap());
+        }
+        assert_eq!(seen, emitted);
+        let inputs: Vec<_> = seen
+            .into_iter()
+            .filter(|e| e == &press || e == &text)
+            .collect();
+        assert_eq!(inputs, vec![press, text]);
+    }
+}
@@ -15,12 +15,40 @@
 
 use std::{
     cmp,
+    task::Poll,
     time::{Duration, Instant},
 };
 
-use input::{AfterRenderArgs, Event, IdleArgs, RenderArgs, UpdateArgs};
+pub use coalesce::Coalesce;
+pub use custom_events::{CustomEvents, EventLoopProxy};
+use input::{
+    event_id::{self, EventId},
+    AfterRenderArgs, Button, ButtonEvent, Event, GenericEvent, IdleArgs, Input, MouseCursorEvent,
+    MouseRelativeEvent, MouseScrollEvent, RenderArgs, SlowUpdateArgs, TouchEvent, UpdateArgs,
+};
+pub use inspect::Inspect;
+pub use on_lag::OnLag;
+pub use pipeline::{EventPipeline, Stage};
+pub use player::EventPlayer;
+pub use prioritize::{Prioritize, EVENT_PRIORITY};
+pub use remapper::Remapper;
+pub use throttle::Throttle;
+pub use timeline::TimelineEvents;
 use window::Window;
 
+mod coalesce;
+mod custom_events;
+mod inspect;
+mod on_lag;
+mod pipeline;
+mod player;
+mod prioritize;
+mod remapper;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+mod throttle;
+mod timeline;
+
 /// Tells whether last emitted event was idle or not.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum Idle {
@@ -38,19 +66,33 @@ enum State {
 }
 
 /// Stores event loop settings.
-#[derive(Copy, Clone, Debug)]
+///
+/// The settings can be copied and reused to create multiple event loops
+/// with identical configuration.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct EventSettings {
     /// The maximum number of frames per second
     ///
     /// The frame rate can be lower because the
     /// next frame is always scheduled from the previous frame.
     /// This causes the frames to "slip" over time.
+    ///
+    /// When set to `0`, frames are rendered as fast as possible, without waiting.
+    /// By default, the frame rate is limited to `DEFAULT_MAX_FPS`.
     pub max_fps: u64,
+    /// Enable or disable matching the frame rate to the monitor showing the window.
+    ///
+    /// When enabled, the maximum frame rate is read from `Window::refresh_rate`
+    /// instead of `max_fps`.
+    /// The event loop falls back to `max_fps` when the window back-end
+    /// can not read the refresh rate or reports an invalid one.
+    pub sync_to_display: bool,
     /// The number of updates per second
     ///
     /// This is the fixed update rate on average over time.
     /// If the event loop lags, it will try to catch up.
     /// When set to `0`, update events are disabled.
+    /// For a variable update rate, see `variable_updates`.
     pub ups: u64,
     /// The number of delayed updates before skipping them to catch up.
     /// When set to `0`, it will always try to catch up.
@@ -65,6 +107,53 @@ pub struct EventSettings {
     /// Enable or disable rendering only when receiving input.
     /// When enabled, update and idle events are disabled.
     pub lazy: bool,
+    /// Enable or disable variable updates.
+    ///
+    /// When enabled, one update event is emitted per frame,
+    /// with `dt` set to the real time elapsed since the previous update.
+    /// The `ups` setting is then only used to disable updates when set to `0`.
+    /// With `max_fps` set to `0`, updates are emitted as fast as possible.
+    ///
+    /// Variable updates suit frame rate independent simulations,
+    /// but the simulation is no longer deterministic,
+    /// and a stall causes a single large update instead of several fixed ones.
+    /// By default, updates use a fixed time step.
+    pub variable_updates: bool,
+    /// The minimum `dt` of variable updates in seconds.
+    ///
+    /// This avoids zero or tiny time steps,
+    /// e.g. for physics integrators that divide by `dt`.
+    pub min_dt: f64,
+    /// The maximum `dt` of variable updates in seconds.
+    ///
+    /// This avoids huge time steps after a stall.
+    pub max_dt: f64,
+    /// The number of times per second to poll input while waiting.
+    ///
+    /// By default, the event loop sleeps until the next update or frame,
+    /// so input is handled at most once per update or frame.
+    /// At low frame and update rates, a higher input rate reduces input latency.
+    /// Input events are buffered by the window back-end between polls,
+    /// so no input is lost either way.
+    /// When set to `0`, input is only polled when the event loop wakes up.
+    pub input_rate: u64,
+    /// The number of slow updates per second.
+    ///
+    /// Slow updates are emitted alongside the regular updates at a lower rate,
+    /// e.g. to run AI at 10 Hz while physics runs at 120 Hz.
+    /// A slow update is emitted directly after the update that completes its time step,
+    /// so slow updates follow the time of the regular updates.
+    /// When set to `0`, slow update events are disabled.
+    pub slow_ups: u64,
+    /// Enable or disable emitting the focus state of the window as the first event.
+    ///
+    /// Window back-ends do not report the focus state on startup,
+    /// so applications that track focus may wait for a focus event that never comes.
+    /// When enabled, the first event is a focus event with the focus state of the window.
+    /// By default, no focus event is emitted until the focus changes.
+    pub initial_focus: bool,
+    /// Options that can be changed without resetting the event loop.
+    pub options: RuntimeOptions,
 }
 
 impl EventSettings {
@@ -72,13 +161,48 @@ impl EventSettings {
     pub fn new() -> EventSettings {
         EventSettings {
             max_fps: DEFAULT_MAX_FPS,
+            sync_to_display: false,
             ups: DEFAULT_UPS,
             swap_buffers: true,
             bench_mode: false,
             lazy: false,
             ups_reset: DEFAULT_UPS_RESET,
+            variable_updates: false,
+            min_dt: DEFAULT_MIN_DT,
+            max_dt: DEFAULT_MAX_DT,
+            input_rate: 0,
+            slow_ups: 0,
+            initial_focus: false,
+            options: RuntimeOptions::new(),
         }
     }
+
+    /// Creates settings that favor a high frame rate over simulation accuracy.
+    ///
+    /// Sets `max_fps` to 144 and `ups` to 60.
+    /// Other fields are the same as in [`new()`](#method.new).
+    ///
+    /// Vertical sync and multisampling are window settings,
+    /// so they are not part of the event loop presets.
+    pub fn performance() -> EventSettings {
+        EventSettings::new().max_fps(144).ups(60)
+    }
+
+    /// Creates settings that balance frame rate and simulation accuracy.
+    ///
+    /// This is the same as [`new()`](#method.new),
+    /// with `max_fps` set to 60 and `ups` set to 120.
+    pub fn balanced() -> EventSettings {
+        EventSettings::new().max_fps(60).ups(120)
+    }
+
+    /// Creates settings that favor simulation accuracy over a high frame rate.
+    ///
+    /// Sets `max_fps` to 60 and `ups` to 240.
+    /// Other fields are the same as in [`new()`](#method.new).
+    pub fn quality() -> EventSettings {
+        EventSettings::new().max_fps(60).ups(240)
+    }
 }
 
 impl Default for EventSettings {
@@ -87,8 +211,282 @@ impl Default for EventSettings {
     }
 }
 
+/// Stores event loop options that can be changed without resetting the event loop.
+///
+/// The options are part of [`EventSettings`](struct.EventSettings.html),
+/// so they can be reused to create multiple event loops.
+/// They are changed at runtime with [`Events::set_options()`](struct.Events.html#method.set_options)
+/// or the individual setters of `Events`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RuntimeOptions {
+    rendering: bool,
+    invert_scroll: [bool; 2],
+    scroll_threshold: f64,
+    clamp_cursor: bool,
+    logical_cursor: bool,
+    center_cursor: bool,
+    disabled_events: u16,
+    render_scale: f64,
+    input_before_update: bool,
+    frame_budget: Option<Duration>,
+    max_frame_time: Option<Duration>,
+    suppress_touch_mouse: Option<Duration>,
+}
+
+impl RuntimeOptions {
+    /// Creates new with default options.
+    pub fn new() -> RuntimeOptions {
+        RuntimeOptions {
+            rendering: true,
+            invert_scroll: [false; 2],
+            scroll_threshold: 0.0,
+            clamp_cursor: false,
+            logical_cursor: false,
+            center_cursor: false,
+            disabled_events: 0,
+            render_scale: 1.0,
+            input_before_update: true,
+            frame_budget: None,
+            max_frame_time: None,
+            suppress_touch_mouse: None,
+        }
+    }
+
+    /// Enable or disable rendering, see [`Events::set_rendering()`](struct.Events.html#method.set_rendering).
+    pub fn set_rendering(&mut self, enable: bool) {
+        self.rendering = enable;
+    }
+
+    /// Enable or disable rendering, see [`Events::set_rendering()`](struct.Events.html#method.set_rendering).
+    pub fn rendering(mut self, enable: bool) -> Self {
+        self.set_rendering(enable);
+        self
+    }
+
+    /// Returns whether rendering is enabled.
+    pub fn get_rendering(&self) -> bool {
+        self.rendering
+    }
+
+    /// Enable or disable inverting the scroll direction per axis, see [`Events::set_invert_scroll()`](struct.Events.html#method.set_invert_scroll).
+    pub fn set_invert_scroll(&mut self, x: bool, y: bool) {
+        self.invert_scroll = [x, y];
+    }
+
+    /// Enable or disable inverting the scroll direction per axis, see [`Events::set_invert_scroll()`](struct.Events.html#method.set_invert_scroll).
+    pub fn invert_scroll(mut self, x: bool, y: bool) -> Self {
+        self.set_invert_scroll(x, y);
+        self
+    }
+
+    /// Returns whether the scroll direction is inverted per axis.
+    pub fn get_invert_scroll(&self) -> [bool; 2] {
+        self.invert_scroll
+    }
+
+    /// Sets the minimum magnitude of mouse scroll events, see [`Events::set_scroll_threshold()`](struct.Events.html#method.set_scroll_threshold).
+    pub fn set_scroll_threshold(&mut self, threshold: f64) {
+        self.scroll_threshold = threshold;
+    }
+
+    /// Sets the minimum magnitude of mouse scroll events, see [`Events::set_scroll_threshold()`](struct.Events.html#method.set_scroll_threshold).
+    pub fn scroll_threshold(mut self, threshold: f64) -> Self {
+        self.set_scroll_threshold(threshold);
+        self
+    }
+
+    /// Returns the minimum magnitude of mouse scroll events.
+    pub fn get_scroll_threshold(&self) -> f64 {
+        self.scroll_threshold
+    }
+
+    /// Enable or disable clamping the mouse cursor to the window, see [`Events::set_clamp_cursor()`](struct.Events.html#method.set_clamp_cursor).
+    pub fn set_clamp_cursor(&mut self, enabled: bool) {
+        self.clamp_cursor = enabled;
+    }
+
+    /// Enable or disable clamping the mouse cursor to the window, see [`Events::set_clamp_cursor()`](struct.Events.html#method.set_clamp_cursor).
+    pub fn clamp_cursor(mut self, enabled: bool) -> Self {
+        self.set_clamp_cursor(enabled);
+        self
+    }
+
+    /// Returns whether the mouse cursor is clamped to the window.
+    pub fn get_clamp_cursor(&self) -> bool {
+        self.clamp_cursor
+    }
+
+    /// Enable or disable normalizing the mouse cursor to logical pixels, see [`Events::set_logical_cursor()`](struct.Events.html#method.set_logical_cursor).
+    pub fn set_logical_cursor(&mut self, enabled: bool) {
+        self.logical_cursor = enabled;
+    }
+
+    /// Enable or disable normalizing the mouse cursor to logical pixels, see [`Events::set_logical_cursor()`](struct.Events.html#method.set_logical_cursor).
+    pub fn logical_cursor(mut self, enabled: bool) -> Self {
+        self.set_logical_cursor(enabled);
+        self
+    }
+
+    /// Returns whether the mouse cursor is normalized to logical pixels.
+    pub fn get_logical_cursor(&self) -> bool {
+        self.logical_cursor
+    }
+
+    /// Enable or disable centering the mouse cursor in the window on startup, see [`Events::set_center_cursor()`](struct.Events.html#method.set_center_cursor).
+    pub fn set_center_cursor(&mut self, enabled: bool) {
+        self.center_cursor = enabled;
+    }
+
+    /// Enable or disable centering the mouse cursor in the window on startup, see [`Events::set_center_cursor()`](struct.Events.html#method.set_center_cursor).
+    pub fn center_cursor(mut self, enabled: bool) -> Self {
+        self.set_center_cursor(enabled);
+        self
+    }
+
+    /// Returns whether the mouse cursor is centered in the window on startup.
+    pub fn get_center_cursor(&self) -> bool {
+        self.center_cursor
+    }
+
+    /// Sets the scale of the draw size in render events, see [`Events::set_render_scale()`](struct.Events.html#method.set_render_scale).
+    pub fn set_render_scale(&mut self, scale: f64) {
+        self.render_scale = scale;
+    }
+
+    /// Sets the scale of the draw size in render events, see [`Events::set_render_scale()`](struct.Events.html#method.set_render_scale).
+    pub fn render_scale(mut self, scale: f64) -> Self {
+        self.set_render_scale(scale);
+        self
+    }
+
+    /// Returns the scale of the draw size in render events.
+    pub fn get_render_scale(&self) -> f64 {
+        self.render_scale
+    }
+
+    /// Sets whether pending input events are emitted before or after update events, see [`Events::set_input_before_update()`](struct.Events.html#method.set_input_before_update).
+    pub fn set_input_before_update(&mut self, enabled: bool) {
+        self.input_before_update = enabled;
+    }
+
+    /// Sets whether pending input events are emitted before or after update events, see [`Events::set_input_before_update()`](struct.Events.html#method.set_input_before_update).
+    pub fn input_before_update(mut self, enabled: bool) -> Self {
+        self.set_input_before_update(enabled);
+        self
+    }
+
+    /// Returns whether pending input events are emitted before update events.
+    pub fn get_input_before_update(&self) -> bool {
+        self.input_before_update
+    }
+
+    /// Enable or disable an input event, see
+    /// [`Events::set_event_enabled()`](struct.Events.html#method.set_event_enabled).
+    pub fn set_event_enabled(&mut self, id: EventId, enabled: bool) {
+        if let Some(bit) = input_event_bit(id) {
+            if enabled {
+                self.disabled_events &= !bit;
+            } else {
+                self.disabled_events |= bit;
+            }
+        }
+    }
+
+    /// Enable or disable an input event, see
+    /// [`Events::set_event_enabled()`](struct.Events.html#method.set_event_enabled).
+    pub fn event_enabled(mut self, id: EventId, enabled: bool) -> Self {
+        self.set_event_enabled(id, enabled);
+        self
+    }
+
+    /// Returns whether an event is enabled.
+    pub fn get_event_enabled(&self, id: EventId) -> bool {
+        !matches!(input_event_bit(id), Some(bit) if self.disabled_events & bit != 0)
+    }
+
+    /// Sets the frame budget in seconds, or `None` to disable it,
+    /// see [`Events::set_frame_budget()`](struct.Events.html#method.set_frame_budget).
+    pub fn set_frame_budget(&mut self, seconds: Option<f64>) {
+        self.frame_budget = seconds.map(Duration::from_secs_f64);
+    }
+
+    /// Sets the frame budget in seconds, or `None` to disable it,
+    /// see [`Events::set_frame_budget()`](struct.Events.html#method.set_frame_budget).
+    pub fn frame_budget(mut self, seconds: Option<f64>) -> Self {
+        self.set_frame_budget(seconds);
+        self
+    }
+
+    /// Returns the frame budget in seconds.
+    pub fn get_frame_budget(&self) -> Option<f64> {
+        self.frame_budget.map(|time| time.as_secs_f64())
+    }
+
+    /// Sets the maximum frame time in seconds, or `None` to disable it,
+    /// see [`Events::set_max_frame_time()`](struct.Events.html#method.set_max_frame_time).
+    pub fn set_max_frame_time(&mut self, seconds: Option<f64>) {
+        self.max_frame_time = seconds.map(Duration::from_secs_f64);
+    }
+
+    /// Sets the maximum frame time in seconds, or `None` to disable it,
+    /// see [`Events::set_max_frame_time()`](struct.Events.html#method.set_max_frame_time).
+    pub fn max_frame_time(mut self, seconds: Option<f64>) -> Self {
+        self.set_max_frame_time(seconds);
+        self
+    }
+
+    /// Returns the maximum frame time in seconds.
+    pub fn get_max_frame_time(&self) -> Option<f64> {
+        self.max_frame_time.map(|time| time.as_secs_f64())
+    }
+
+    /// Sets the time in seconds to suppress mouse events after a touch event, or `None` to disable it,
+    /// see [`Events::set_suppress_touch_mouse()`](struct.Events.html#method.set_suppress_touch_mouse).
+    pub fn set_suppress_touch_mouse(&mut self, seconds: Option<f64>) {
+        self.suppress_touch_mouse = seconds.map(Duration::from_secs_f64);
+    }
+
+    /// Sets the time in seconds to suppress mouse events after a touch event, or `None` to disable it,
+    /// see [`Events::set_suppress_touch_mouse()`](struct.Events.html#method.set_suppress_touch_mouse).
+    pub fn suppress_touch_mouse(mut self, seconds: Option<f64>) -> Self {
+        self.set_suppress_touch_mouse(seconds);
+        self
+    }
+
+    /// Returns the time in seconds to suppress mouse events after a touch event.
+    pub fn get_suppress_touch_mouse(&self) -> Option<f64> {
+        self.suppress_touch_mouse.map(|time| time.as_secs_f64())
+    }
+}
+
+impl Default for RuntimeOptions {
+    fn default() -> RuntimeOptions {
+        RuntimeOptions::new()
+    }
+}
+
 /// An event loop iterator
 ///
+/// The window is borrowed only for each call to [`next()`](#method.next),
+/// so it can be used between events without shared ownership:
+///
+/// ```
+/// use event_loop::{EventSettings, Events};
+/// use window::{NoWindow, Window, WindowSettings};
+///
+/// let mut window = NoWindow::new(&WindowSettings::new("example", [640, 480]));
+/// let mut events = Events::new(EventSettings::new());
+/// while let Some(_e) = events.next(&mut window) {
+///     window.set_should_close(true);
+/// }
+/// assert!(window.should_close());
+/// ```
+///
+/// An event loop can not be woken up from other threads by itself.
+/// Wrap it in [`CustomEvents`](struct.CustomEvents.html)
+/// and use a proxy from [`CustomEvents::create_proxy()`](struct.CustomEvents.html#method.create_proxy)
+/// to request a redraw, wake up a lazy event loop or send custom events.
+///
 /// *Warning: Because the iterator polls events from the window back-end,
 /// it must be used on the same thread as the window back-end (usually main thread),
 /// unless the window back-end supports multi-thread event polling.*
@@ -100,76 +498,653 @@ pub struct Events {
     dt_update_in_ns: u64,
     dt_frame_in_ns: u64,
     dt: f64,
+    slow_update_time: f64,
+    slow_update_due: bool,
     settings: EventSettings,
     first_frame: bool,
+    first_event: bool,
+    lag: f64,
+    last_touch: Option<Instant>,
+    last_event_time: Instant,
 }
 
 static BILLION: u64 = 1_000_000_000;
 
+/// How often a lazy event loop calls its wait hook while waiting for input.
+const WAIT_HOOK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Tells a lazy event loop waiting for input what to do next.
+enum Wakeup {
+    /// Continue waiting for input.
+    Wait,
+    /// Handle pending input and render, as after receiving input.
+    Redraw,
+    /// Emit an event and continue waiting.
+    Emit(Event),
+}
+
+/// Input events that can be disabled with `Events::set_event_enabled`.
+const INPUT_EVENTS: [EventId; 13] = [
+    event_id::BUTTON,
+    event_id::CLOSE,
+    event_id::CONTROLLER_AXIS,
+    event_id::CURSOR,
+    event_id::FILE_DRAG,
+    event_id::FOCUS,
+    event_id::MOUSE_CURSOR,
+    event_id::MOUSE_RELATIVE,
+    event_id::MOUSE_SCROLL,
+    event_id::RESIZE,
+    event_id::SCALE_FACTOR_CHANGED,
+    event_id::TEXT,
+    event_id::TOUCH,
+];
+
+/// Returns the bit of an input event in the set of disabled events.
+fn input_event_bit(id: EventId) -> Option<u16> {
+    INPUT_EVENTS.iter().position(|&x| x == id).map(|i| 1 << i)
+}
+
 fn ns_to_duration(ns: u64) -> Duration {
     let secs = ns / BILLION;
     let nanos = (ns % BILLION) as u32;
     Duration::new(secs, nanos)
 }
 
+/// Clamps the time of the last update to at most `max_frame_time` before `current_time`.
+fn clamp_last_update(
+    last_update: Instant,
+    current_time: Instant,
+    max_frame_time: Option<Duration>,
+) -> Instant {
+    match max_frame_time.and_then(|max| current_time.checked_sub(max)) {
+        Some(earliest) => last_update.max(earliest),
+        None => last_update,
+    }
+}
+
 fn duration_to_secs(dur: Duration) -> f64 {
     dur.as_secs() as f64 + dur.subsec_nanos() as f64 / 1_000_000_000.0
 }
 
+#[cfg(not(test))]
+fn now() -> Instant {
+    Instant::now()
+}
+
+#[cfg(not(test))]
+fn sleep(dur: Duration) {
+    spin_sleep::sleep(dur);
+}
+
+#[cfg(test)]
+use tests::clock::{now, sleep};
+
 /// The default updates per second.
 pub const DEFAULT_UPS: u64 = 120;
 /// The default delayed updates reset.
 pub const DEFAULT_UPS_RESET: u64 = 2;
 /// The default maximum frames per second.
 pub const DEFAULT_MAX_FPS: u64 = 60;
+/// The default minimum `dt` of variable updates in seconds.
+pub const DEFAULT_MIN_DT: f64 = 1e-6;
+/// The default maximum `dt` of variable updates in seconds.
+pub const DEFAULT_MAX_DT: f64 = 0.25;
 
 impl Events {
     /// Creates a new event iterator with default UPS and FPS settings.
     pub fn new(settings: EventSettings) -> Events {
-        let start = Instant::now();
+        let start = now();
         Events {
             state: State::Render,
             last_update: start,
             last_frame: start,
-            dt_update_in_ns: if settings.ups == 0 {
-                0
-            } else {
-                BILLION / settings.ups
-            },
-            dt_frame_in_ns: BILLION / settings.max_fps,
+            dt_update_in_ns: BILLION.checked_div(settings.ups).unwrap_or(0),
+            // Updated from the window when polling if synced to the display.
+            dt_frame_in_ns: BILLION.checked_div(settings.max_fps).unwrap_or(0),
             dt: if settings.ups == 0 {
                 0.0
             } else {
                 1.0 / settings.ups as f64
             },
+            slow_update_time: 0.0,
+            slow_update_due: false,
             settings,
             first_frame: true,
+            first_event: true,
+            lag: 0.0,
+            last_touch: None,
+            last_event_time: start,
+        }
+    }
+
+    /// Sets the options that can be changed without resetting the event loop.
+    pub fn set_options(&mut self, options: RuntimeOptions) {
+        self.settings.options = options;
+    }
+
+    /// Returns the options that can be changed without resetting the event loop.
+    pub fn get_options(&self) -> RuntimeOptions {
+        self.settings.options
+    }
+
+    /// Enable or disable rendering.
+    ///
+    /// When disabled, render events are not emitted and buffers are not swapped,
+    /// while update and input events continue as normal.
+    /// This is useful to save GPU time, e.g. when the window is minimized.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_rendering(&mut self, enable: bool) {
+        self.settings.options.set_rendering(enable);
+    }
+
+    /// Returns whether rendering is enabled.
+    pub fn get_rendering(&self) -> bool {
+        self.settings.options.get_rendering()
+    }
+
+    /// Enable or disable inverting the scroll direction per axis.
+    ///
+    /// When enabled for an axis, the corresponding component of
+    /// mouse scroll events is negated before the event is emitted,
+    /// e.g. to respect a "natural scrolling" preference.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_invert_scroll(&mut self, x: bool, y: bool) {
+        self.settings.options.set_invert_scroll(x, y);
+    }
+
+    /// Returns whether the scroll direction is inverted per axis.
+    pub fn get_invert_scroll(&self) -> [bool; 2] {
+        self.settings.options.get_invert_scroll()
+    }
+
+    /// Sets the minimum magnitude of mouse scroll events.
+    ///
+    /// The threshold is applied per axis.
+    /// Components of mouse scroll events with a smaller magnitude are set to zero,
+    /// and events where all components are zero are dropped,
+    /// e.g. to ignore micro-scrolls from fingers resting on a trackpad.
+    /// The default is `0.0`, which keeps all scroll events.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_scroll_threshold(&mut self, threshold: f64) {
+        self.settings.options.set_scroll_threshold(threshold);
+    }
+
+    /// Returns the minimum magnitude of mouse scroll events.
+    pub fn get_scroll_threshold(&self) -> f64 {
+        self.settings.options.get_scroll_threshold()
+    }
+
+    /// Enable or disable clamping the mouse cursor to the window.
+    ///
+    /// When enabled, mouse cursor events outside the window size,
+    /// e.g. while dragging outside the window, are clamped to the window bounds.
+    /// Mouse relative events are not changed.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_clamp_cursor(&mut self, enabled: bool) {
+        self.settings.options.set_clamp_cursor(enabled);
+    }
+
+    /// Returns whether the mouse cursor is clamped to the window.
+    pub fn get_clamp_cursor(&self) -> bool {
+        self.settings.options.get_clamp_cursor()
+    }
+
+    /// Enable or disable normalizing the mouse cursor to logical pixels.
+    ///
+    /// Window back-ends disagree on whether mouse cursor coordinates
+    /// are in logical or physical pixels on high DPI displays.
+    /// When enabled, mouse cursor events are assumed to be in physical pixels
+    /// and are divided by the scale factor of the window,
+    /// such that they use the same logical coordinates as the window size.
+    /// Cursor clamping is applied after normalizing.
+    /// Use this for back-ends that report physical cursor coordinates.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_logical_cursor(&mut self, enabled: bool) {
+        self.settings.options.set_logical_cursor(enabled);
+    }
+
+    /// Returns whether the mouse cursor is normalized to logical pixels.
+    pub fn get_logical_cursor(&self) -> bool {
+        self.settings.options.get_logical_cursor()
+    }
+
+    /// Enable or disable centering the mouse cursor in the window on startup.
+    ///
+    /// When enabled, the cursor is moved to the center of the window
+    /// before emitting the first event.
+    /// This requires a window back-end that can move the cursor.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_center_cursor(&mut self, enabled: bool) {
+        self.settings.options.set_center_cursor(enabled);
+    }
+
+    /// Returns whether the mouse cursor is centered in the window on startup.
+    pub fn get_center_cursor(&self) -> bool {
+        self.settings.options.get_center_cursor()
+    }
+
+    /// Enable or disable an input event, e.g. `event_id::MOUSE_RELATIVE`.
+    ///
+    /// Disabled events are dropped as soon as they are received from the window,
+    /// for applications that never handle them.
+    /// Only input events can be disabled,
+    /// so other event ids, such as update or render, are ignored.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_event_enabled(&mut self, id: EventId, enabled: bool) {
+        self.settings.options.set_event_enabled(id, enabled);
+    }
+
+    /// Returns whether an event is enabled.
+    pub fn get_event_enabled(&self, id: EventId) -> bool {
+        self.settings.options.get_event_enabled(id)
+    }
+
+    /// Sets the scale of the draw size in render events.
+    ///
+    /// This is used to render at a multiple of the window resolution,
+    /// e.g. `2.0` for supersampling or `0.5` for rendering at half resolution.
+    /// The window size in points is not changed.
+    /// The graphics back-end must honor the draw size of render events,
+    /// e.g. by rendering to a texture of that size.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_render_scale(&mut self, scale: f64) {
+        self.settings.options.set_render_scale(scale);
+    }
+
+    /// Returns the scale of the draw size in render events.
+    pub fn get_render_scale(&self) -> f64 {
+        self.settings.options.get_render_scale()
+    }
+
+    /// Sets whether pending input events are emitted before or after update events.
+    ///
+    /// By default, all pending input is handled before updating,
+    /// so an update sees the latest input.
+    /// When disabled, update events are emitted first,
+    /// and pending input is handled afterwards.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_input_before_update(&mut self, enabled: bool) {
+        self.settings.options.set_input_before_update(enabled);
+    }
+
+    /// Returns whether pending input events are emitted before update events.
+    pub fn get_input_before_update(&self) -> bool {
+        self.settings.options.get_input_before_update()
+    }
+
+    /// Sets the frame budget in seconds, or `None` to disable it.
+    ///
+    /// When a frame is late by more than the budget,
+    /// e.g. because an update took too long, rendering is skipped for that frame.
+    /// This keeps input latency low in soft real-time applications.
+    /// The frame budget is ignored in benchmark mode and lazy mode.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_frame_budget(&mut self, seconds: Option<f64>) {
+        self.settings.options.set_frame_budget(seconds);
+    }
+
+    /// Returns the frame budget in seconds.
+    pub fn get_frame_budget(&self) -> Option<f64> {
+        self.settings.options.get_frame_budget()
+    }
+
+    /// Sets the maximum frame time in seconds, or `None` to disable it.
+    ///
+    /// The time since the last update is clamped to the maximum frame time,
+    /// both for accumulating updates and for extrapolating render events.
+    /// This avoids a large jump after a one-off long frame,
+    /// e.g. when the application stalls while loading.
+    /// Time beyond the maximum frame time is dropped.
+    /// The maximum frame time is ignored in benchmark mode and lazy mode.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_max_frame_time(&mut self, seconds: Option<f64>) {
+        self.settings.options.set_max_frame_time(seconds);
+    }
+
+    /// Returns the maximum frame time in seconds.
+    pub fn get_max_frame_time(&self) -> Option<f64> {
+        self.settings.options.get_max_frame_time()
+    }
+
+    /// Sets the time in seconds to suppress mouse events after a touch event,
+    /// or `None` to disable it.
+    ///
+    /// Some platforms generate synthetic mouse events from touch input,
+    /// which would otherwise be handled twice.
+    /// Synthetic mouse events are not flagged by window back-ends,
+    /// so they are correlated by time instead:
+    /// Mouse cursor, mouse relative and mouse button events
+    /// are dropped when they arrive within this time after a touch event.
+    ///
+    /// Unlike the other event loop settings, this does not reset the event loop.
+    pub fn set_suppress_touch_mouse(&mut self, seconds: Option<f64>) {
+        self.settings.options.set_suppress_touch_mouse(seconds);
+    }
+
+    /// Returns the time in seconds to suppress mouse events after a touch event.
+    pub fn get_suppress_touch_mouse(&self) -> Option<f64> {
+        self.settings.options.get_suppress_touch_mouse()
+    }
+
+    /// Returns the time in seconds until the next update or render is due,
+    /// e.g. for waiting on other tasks in an async runtime meanwhile.
+    ///
+    /// Returns `0.0` if the event loop is ready now, e.g. in benchmark mode.
+    /// In lazy mode, the event loop waits for input,
+    /// so this returns infinity while waiting.
+    pub fn time_until_next(&self) -> f64 {
+        if self.settings.bench_mode || self.slow_update_due {
+            return 0.0;
+        }
+        if !matches!(self.state, State::UpdateLoop(_)) {
+            return 0.0;
+        }
+        if self.settings.lazy {
+            return f64::INFINITY;
+        }
+        let next_frame = self.last_frame + ns_to_duration(self.dt_frame_in_ns);
+        let next_event = if self.settings.ups == 0 || self.settings.variable_updates {
+            next_frame
+        } else {
+            let next_update = self.last_update + ns_to_duration(self.dt_update_in_ns);
+            cmp::min(next_frame, next_update)
+        };
+        duration_to_secs(next_event.saturating_duration_since(now()))
+    }
+
+    /// Returns how late the latest update event was in seconds.
+    ///
+    /// This is the time between when the update was scheduled and when it was emitted.
+    /// It is zero in benchmark mode and with variable updates.
+    pub fn get_lag(&self) -> f64 {
+        self.lag
+    }
+
+    /// Returns true if a frame can be rendered.
+    ///
+    /// Rendering is skipped when disabled, when the window is minimized,
+    /// or when the window has zero width or height.
+    fn can_render<W: Window>(&self, window: &W) -> bool {
+        let size = window.size();
+        self.settings.options.rendering
+            && !window.is_minimized()
+            && size.width != 0.0
+            && size.height != 0.0
+    }
+
+    /// Returns the draw size of render events, scaled by the render scale.
+    fn draw_size<W: Window>(&self, window: &W) -> [u32; 2] {
+        let draw_size = window.draw_size();
+        if self.settings.options.render_scale == 1.0 {
+            return draw_size.into();
         }
+        [
+            (draw_size.width * self.settings.options.render_scale).round() as u32,
+            (draw_size.height * self.settings.options.render_scale).round() as u32,
+        ]
     }
 
     /// Returns the next event.
+    ///
+    /// When `initial_focus` is enabled in the settings,
+    /// the first event is a focus event with the initial focus state of the window.
+    /// Like other input events, it is dropped when focus events are disabled.
     pub fn next<W>(&mut self, window: &mut W) -> Option<Event>
     where
         W: Window,
     {
-        if self.settings.lazy || self.settings.ups == 0 {
-            // This mode does not emit update events.
-            // More commonly used in UI applications.
-            if window.should_close() {
-                return None;
-            }
-            match self.state {
-                State::SwapBuffers => {
-                    if self.settings.swap_buffers {
-                        window.swap_buffers();
-                    }
-                    // This mode needs no `Render` state.
-                    self.state = State::UpdateLoop(Idle::No);
-                    return Some(AfterRenderArgs.into());
+        self.next_with_hook(window, None)
+    }
+
+    /// Returns the next event, calling a hook while waiting for input in lazy mode.
+    ///
+    /// Without a hook, a lazy event loop blocks until the next input event.
+    /// With a hook, it waits for input in short steps and calls the hook before each step,
+    /// e.g. to handle requests from other threads.
+    fn next_with_hook<'a, W>(
+        &mut self,
+        window: &mut W,
+        mut hook: Option<&mut (dyn FnMut() -> Wakeup + 'a)>,
+    ) -> Option<Event>
+    where
+        W: Window,
+    {
+        let initial_focus = self.first_event(window);
+        let e = match initial_focus.and_then(|e| self.apply_input_options(window, e)) {
+            Some(e) => e,
+            None => loop {
+                let e = self.poll(window, hook.as_deref_mut())?;
+                if let Some(e) = self.apply_input_options(window, e) {
+                    break e;
                 }
-                State::HandleEvents => {
-                    if !self.settings.bench_mode {
-                        // Poll input events until event queue is empty.
+            },
+        };
+        self.last_event_time = now();
+        Some(e)
+    }
+
+    /// Prepares the window before the first event,
+    /// and returns the initial focus event if enabled.
+    fn first_event<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        if !self.first_event {
+            return None;
+        }
+        self.first_event = false;
+        if self.settings.options.center_cursor {
+            let size = window.size();
+            window.set_cursor_position([size.width / 2.0, size.height / 2.0]);
+        }
+        if self.settings.initial_focus {
+            Some(Input::Focus(window.is_focused()).into())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the time when the latest event was emitted,
+    /// e.g. for profiling tools that need an absolute timestamp.
+    pub fn get_last_event_time(&self) -> Instant {
+        self.last_event_time
+    }
+
+    /// Folds all events until the window closes,
+    /// e.g. to compute aggregate statistics of a session.
+    pub fn fold_events<W, B, F>(mut self, window: &mut W, init: B, mut f: F) -> B
+    where
+        W: Window,
+        F: FnMut(B, &Event) -> B,
+    {
+        let mut acc = init;
+        while let Some(e) = self.next(window) {
+            acc = f(acc, &e);
+        }
+        acc
+    }
+
+    /// Resets the timing of updates and frames to the current time,
+    /// e.g. after a modal dialog or a loading pause.
+    ///
+    /// The event loop then continues as if the pause did not happen,
+    /// instead of catching up on the lost time with a burst of updates.
+    pub fn reset_timing(&mut self) {
+        let current_time = now();
+        self.last_update = current_time;
+        self.last_frame = current_time;
+        self.lag = 0.0;
+    }
+
+    /// Discards all pending input events of the window without emitting them,
+    /// e.g. to drop stale input after a loading screen.
+    ///
+    /// Update and render scheduling is not changed.
+    pub fn flush_inputs<W>(&mut self, window: &mut W)
+    where
+        W: Window,
+    {
+        while window.poll_event().is_some() {}
+    }
+
+    /// Returns the next event without waiting,
+    /// e.g. for running the event loop inside an async executor.
+    ///
+    /// Returns `Poll::Pending` when the event loop would otherwise wait
+    /// for the next update or render and there are no input events,
+    /// see [`time_until_next()`](#method.time_until_next).
+    /// Idle events are not emitted while pending.
+    /// In lazy mode, this returns `Poll::Pending` until there is input.
+    pub fn poll_event<W>(&mut self, window: &mut W) -> Poll<Option<Event>>
+    where
+        W: Window,
+    {
+        if !self.first_event && self.time_until_next() > 0.0 {
+            if window.should_close() {
+                return Poll::Ready(None);
+            }
+            let e = match window.poll_event() {
+                Some(e) => e,
+                None => return Poll::Pending,
+            };
+            if self.settings.lazy {
+                // Handle rest of events before rendering.
+                self.state = State::HandleEvents;
+            }
+            return match self.apply_input_options(window, e) {
+                Some(e) => {
+                    self.last_event_time = now();
+                    Poll::Ready(Some(e))
+                }
+                None => Poll::Pending,
+            };
+        }
+        Poll::Ready(self.next(window))
+    }
+
+    /// Applies input options to an event, such as inverting the scroll direction.
+    ///
+    /// Returns `None` if the event is dropped.
+    fn apply_input_options<W>(&mut self, window: &W, e: Event) -> Option<Event>
+    where
+        W: Window,
+    {
+        let options = self.settings.options;
+        if options.disabled_events != 0 && !options.get_event_enabled(e.event_id()) {
+            return None;
+        }
+        if let Some(timeout) = options.suppress_touch_mouse {
+            let current_time = now();
+            if e.touch_args().is_some() {
+                self.last_touch = Some(current_time);
+            } else if let Some(last_touch) = self.last_touch {
+                let is_mouse = e.mouse_cursor_args().is_some()
+                    || e.mouse_relative_args().is_some()
+                    || matches!(e.button_args(), Some(args) if matches!(args.button, Button::Mouse(_)));
+                if is_mouse && current_time.saturating_duration_since(last_touch) <= timeout {
+                    return None;
+                }
+            }
+        }
+        if options.invert_scroll != [false; 2] || options.scroll_threshold > 0.0 {
+            if let Some(mut pos) = e.mouse_scroll_args() {
+                for (i, &invert) in options.invert_scroll.iter().enumerate() {
+                    if pos[i].abs() < options.scroll_threshold {
+                        pos[i] = 0.0;
+                    } else if invert {
+                        pos[i] = -pos[i];
+                    }
+                }
+                if pos == [0.0; 2] {
+                    return None;
+                }
+                return MouseScrollEvent::from_pos(pos, &e);
+            }
+        }
+        if options.logical_cursor || options.clamp_cursor {
+            if let Some(mut pos) = e.mouse_cursor_args() {
+                if options.logical_cursor {
+                    let scale = window.scale_factor();
+                    pos = [pos[0] / scale, pos[1] / scale];
+                }
+                if options.clamp_cursor {
+                    let size = window.size();
+                    pos = [
+                        pos[0].max(0.0).min(size.width),
+                        pos[1].max(0.0).min(size.height),
+                    ];
+                }
+                return MouseCursorEvent::from_pos(pos, &e);
+            }
+        }
+        Some(e)
+    }
+
+    /// Accumulates update time and schedules a slow update when it is due.
+    fn accumulate_slow_update(&mut self, dt: f64) {
+        if self.settings.slow_ups == 0 {
+            return;
+        }
+        let slow_dt = 1.0 / self.settings.slow_ups as f64;
+        self.slow_update_time += dt;
+        // Allow for rounding errors when adding up fixed time steps.
+        if self.slow_update_time + 1e-9 >= slow_dt {
+            self.slow_update_time -= slow_dt;
+            self.slow_update_due = true;
+        }
+    }
+
+    /// Returns the next event before applying input options.
+    fn poll<W>(
+        &mut self,
+        window: &mut W,
+        mut hook: Option<&mut (dyn FnMut() -> Wakeup + '_)>,
+    ) -> Option<Event>
+    where
+        W: Window,
+    {
+        if self.slow_update_due {
+            self.slow_update_due = false;
+            let dt = 1.0 / self.settings.slow_ups as f64;
+            return Some(SlowUpdateArgs { dt }.into());
+        }
+        if self.settings.sync_to_display {
+            self.dt_frame_in_ns = match window.refresh_rate() {
+                // Ignore invalid rates, and rates too low for a display.
+                Some(rate) if rate.is_finite() && rate >= 1.0 => (BILLION as f64 / rate) as u64,
+                _ => BILLION.checked_div(self.settings.max_fps).unwrap_or(0),
+            };
+        }
+        if self.settings.lazy || self.settings.ups == 0 {
+            // This mode does not emit update events.
+            // More commonly used in UI applications.
+            if window.should_close() {
+                return None;
+            }
+            match self.state {
+                State::SwapBuffers => {
+                    if self.settings.swap_buffers {
+                        window.swap_buffers();
+                    }
+                    // This mode needs no `Render` state.
+                    self.state = State::UpdateLoop(Idle::No);
+                    return Some(AfterRenderArgs.into());
+                }
+                State::HandleEvents => {
+                    if !self.settings.bench_mode {
+                        // Poll input events until event queue is empty.
                         if let Some(ev) = window.poll_event() {
                             return Some(ev);
                         }
@@ -200,14 +1175,33 @@ impl Events {
                     if self.settings.lazy {
                         // A lazy event loop always waits until next event, ignoring time to render.
                         if let State::UpdateLoop(_) = self.state {
-                            // Wait for next input event.
-                            let ev = window.wait_event();
-                            // Handle rest of events before rendering.
-                            self.state = State::HandleEvents;
-                            return Some(ev);
+                            let hook = match hook.as_deref_mut() {
+                                Some(hook) => hook,
+                                None => {
+                                    // Wait for next input event.
+                                    let ev = window.wait_event();
+                                    // Handle rest of events before rendering.
+                                    self.state = State::HandleEvents;
+                                    return Some(ev);
+                                }
+                            };
+                            match hook() {
+                                Wakeup::Wait => {
+                                    // Wait for input in steps, to call the hook regularly.
+                                    let ev = window.wait_event_timeout(WAIT_HOOK_INTERVAL);
+                                    if let Some(ev) = ev {
+                                        // Handle rest of events before rendering.
+                                        self.state = State::HandleEvents;
+                                        return Some(ev);
+                                    }
+                                }
+                                Wakeup::Redraw => self.state = State::HandleEvents,
+                                Wakeup::Emit(e) => return Some(e),
+                            }
+                            continue;
                         }
                     } else {
-                        let current_time = Instant::now();
+                        let current_time = now();
                         let next_frame = self.last_frame + ns_to_duration(self.dt_frame_in_ns);
                         if !self.first_frame && next_frame > current_time {
                             if let State::UpdateLoop(Idle::No) = self.state {
@@ -232,23 +1226,23 @@ impl Events {
                 self.first_frame = false;
 
                 // In normal mode, let the FPS slip if late.
-                self.last_frame = Instant::now();
+                self.last_frame = now();
 
-                let size = window.size();
-                let draw_size = window.draw_size();
-                if size.width != 0.0 && size.height != 0.0 {
+                if self.can_render(window) {
+                    let size = window.size();
+                    let draw_size = self.draw_size(window);
                     // Swap buffers next time.
                     self.state = State::SwapBuffers;
                     return Some(
                         RenderArgs {
                             ext_dt: 0.0,
                             window_size: size.into(),
-                            draw_size: draw_size.into(),
+                            draw_size,
                         }
                         .into(),
                     );
                 } else {
-                    // Can not render at this time.
+                    // Can not render at this time, or rendering is disabled.
                     self.state = State::UpdateLoop(Idle::No);
                 }
             }
@@ -277,17 +1271,28 @@ impl Events {
                         return None;
                     }
 
+                    let scheduled = self.last_frame + ns_to_duration(self.dt_frame_in_ns);
+                    let mut over_budget = false;
                     if self.settings.bench_mode {
                         // In benchmark mode, pretend FPS is perfect.
                         self.last_frame += ns_to_duration(self.dt_frame_in_ns);
                     } else {
                         // In normal mode, let the FPS slip if late.
-                        self.last_frame = Instant::now();
+                        self.last_frame = now();
+                        self.last_update = clamp_last_update(
+                            self.last_update,
+                            self.last_frame,
+                            self.settings.options.max_frame_time,
+                        );
+                        if let Some(budget) = self.settings.options.frame_budget {
+                            over_budget =
+                                self.last_frame.saturating_duration_since(scheduled) > budget;
+                        }
                     }
 
-                    let size = window.size();
-                    let draw_size = window.draw_size();
-                    if size.width != 0.0 && size.height != 0.0 {
+                    if !over_budget && self.can_render(window) {
+                        let size = window.size();
+                        let draw_size = self.draw_size(window);
                         // Swap buffers next time.
                         self.state = State::SwapBuffers;
                         return Some(
@@ -297,7 +1302,7 @@ impl Events {
                                     self.last_frame.duration_since(self.last_update),
                                 ),
                                 window_size: size.into(),
-                                draw_size: draw_size.into(),
+                                draw_size,
                             }
                             .into(),
                         );
@@ -309,7 +1314,12 @@ impl Events {
                     if self.settings.swap_buffers {
                         window.swap_buffers();
                     }
-                    self.state = State::UpdateLoop(Idle::No);
+                    self.state = if self.settings.variable_updates {
+                        // Update once per frame.
+                        State::HandleEvents
+                    } else {
+                        State::UpdateLoop(Idle::No)
+                    };
                     return Some(AfterRenderArgs.into());
                 }
                 State::UpdateLoop(ref mut idle) => {
@@ -319,7 +1329,12 @@ impl Events {
                         // This is to avoid the input events affecting
                         // the application state when benchmarking.
                         let next_frame = self.last_frame + ns_to_duration(self.dt_frame_in_ns);
-                        let next_update = self.last_update + ns_to_duration(self.dt_update_in_ns);
+                        let next_update = if self.settings.variable_updates {
+                            // Update after rendering instead.
+                            next_frame
+                        } else {
+                            self.last_update + ns_to_duration(self.dt_update_in_ns)
+                        };
                         let next_event = cmp::min(next_frame, next_update);
                         if next_event == next_frame {
                             State::Render
@@ -327,9 +1342,19 @@ impl Events {
                             State::HandleEvents
                         }
                     } else {
-                        let current_time = Instant::now();
+                        let current_time = now();
+                        self.last_update = clamp_last_update(
+                            self.last_update,
+                            current_time,
+                            self.settings.options.max_frame_time,
+                        );
                         let next_frame = self.last_frame + ns_to_duration(self.dt_frame_in_ns);
-                        let next_update = self.last_update + ns_to_duration(self.dt_update_in_ns);
+                        let next_update = if self.settings.variable_updates {
+                            // Update after rendering instead.
+                            next_frame
+                        } else {
+                            self.last_update + ns_to_duration(self.dt_update_in_ns)
+                        };
                         let next_event = cmp::min(next_frame, next_update);
                         if next_event > current_time {
                             if let Some(x) = window.poll_event() {
@@ -340,7 +1365,16 @@ impl Events {
                                 let seconds = duration_to_secs(next_event - current_time);
                                 return Some(IdleArgs { dt: seconds }.into());
                             }
-                            spin_sleep::sleep(next_event - current_time);
+                            let wait = next_event - current_time;
+                            if let Some(ns) = BILLION.checked_div(self.settings.input_rate) {
+                                let interval = ns_to_duration(ns);
+                                if interval < wait {
+                                    // Wake up early to poll input.
+                                    sleep(interval);
+                                    continue;
+                                }
+                            }
+                            sleep(wait);
                             State::UpdateLoop(Idle::No)
                         } else if next_event == next_frame {
                             State::Render
@@ -358,6 +1392,9 @@ impl Events {
                             None => State::Update,
                             Some(_) => State::HandleEvents,
                         }
+                    } else if !self.settings.options.input_before_update {
+                        // Handle events after updating.
+                        State::Update
                     } else {
                         // Handle all events before updating.
                         match window.poll_event() {
@@ -368,17 +1405,38 @@ impl Events {
                 }
                 State::Update => {
                     self.state = State::UpdateLoop(Idle::No);
+                    if self.settings.variable_updates {
+                        let current_time = now();
+                        self.last_update = clamp_last_update(
+                            self.last_update,
+                            current_time,
+                            self.settings.options.max_frame_time,
+                        );
+                        let dt = duration_to_secs(current_time - self.last_update)
+                            .max(self.settings.min_dt)
+                            .min(self.settings.max_dt);
+                        self.last_update = current_time;
+                        self.accumulate_slow_update(dt);
+                        return Some(UpdateArgs { dt }.into());
+                    }
+                    self.lag = if self.settings.bench_mode {
+                        0.0
+                    } else {
+                        let scheduled = self.last_update + ns_to_duration(self.dt_update_in_ns);
+                        duration_to_secs(now().saturating_duration_since(scheduled))
+                    };
                     if !self.settings.bench_mode
                         && self.settings.ups_reset > 0
-                        && Instant::now() - self.last_update
+                        && now() - self.last_update
                             > ns_to_duration(self.settings.ups_reset * self.dt_update_in_ns)
                     {
                         // Skip updates because CPU is too busy.
-                        self.last_update = Instant::now();
+                        self.last_update = now();
                     } else {
                         // Use the update state stored right after sleep.
                         self.last_update += ns_to_duration(self.dt_update_in_ns);
                     }
+                    self.accumulate_slow_update(self.dt);
                     return Some(UpdateArgs { dt: self.dt }.into());
                 }
             };
@@ -438,6 +1496,7 @@ pub trait EventLoop: Sized {
     /// The frame rate can be lower because the
     /// next frame is always scheduled from the previous frame.
     /// This causes the frames to "slip" over time.
+    /// When set to `0`, frames are rendered as fast as possible, without waiting.
     fn set_max_fps(&mut self, frames: u64) {
         let old_settings = self.get_event_settings();
         self.set_event_settings(EventSettings {
@@ -451,11 +1510,31 @@ pub trait EventLoop: Sized {
     /// The frame rate can be lower because the
     /// next frame is always scheduled from the previous frame.
     /// This causes the frames to "slip" over time.
+    /// When set to `0`, frames are rendered as fast as possible, without waiting.
     fn max_fps(mut self, frames: u64) -> Self {
         self.set_max_fps(frames);
         self
     }
 
+    /// Enable or disable matching the frame rate to the monitor showing the window.
+    ///
+    /// Falls back to `max_fps` when the refresh rate is not available.
+    fn set_sync_to_display(&mut self, enable: bool) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings {
+            sync_to_display: enable,
+            ..old_settings
+        })
+    }
+
+    /// Enable or disable matching the frame rate to the monitor showing the window.
+    ///
+    /// Falls back to `max_fps` when the refresh rate is not available.
+    fn sync_to_display(mut self, enable: bool) -> Self {
+        self.set_sync_to_display(enable);
+        self
+    }
+
     /// Enable or disable automatic swapping of buffers.
     fn set_swap_buffers(&mut self, enable: bool) {
         let old_settings = self.get_event_settings();
@@ -510,6 +1589,136 @@ pub trait EventLoop: Sized {
         self.set_lazy(enable);
         self
     }
+
+    /// Enable or disable variable updates.
+    ///
+    /// When enabled, one update event is emitted per frame,
+    /// with `dt` set to the real time elapsed since the previous update.
+    /// The `ups` setting is then only used to disable updates when set to `0`.
+    ///
+    /// Variable updates suit frame rate independent simulations,
+    /// but the simulation is no longer deterministic,
+    /// and a stall causes a single large update instead of several fixed ones.
+    /// By default, updates use a fixed time step.
+    fn set_variable_updates(&mut self, enable: bool) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings {
+            variable_updates: enable,
+            ..old_settings
+        })
+    }
+
+    /// Enable or disable variable updates.
+    ///
+    /// When enabled, one update event is emitted per frame,
+    /// with `dt` set to the real time elapsed since the previous update.
+    /// The `ups` setting is then only used to disable updates when set to `0`.
+    ///
+    /// Variable updates suit frame rate independent simulations,
+    /// but the simulation is no longer deterministic,
+    /// and a stall causes a single large update instead of several fixed ones.
+    /// By default, updates use a fixed time step.
+    fn variable_updates(mut self, enable: bool) -> Self {
+        self.set_variable_updates(enable);
+        self
+    }
+
+    /// The minimum `dt` of variable updates in seconds.
+    ///
+    /// This avoids zero or tiny time steps,
+    /// e.g. for physics integrators that divide by `dt`.
+    fn set_min_dt(&mut self, dt: f64) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings {
+            min_dt: dt,
+            ..old_settings
+        })
+    }
+
+    /// The minimum `dt` of variable updates in seconds.
+    ///
+    /// This avoids zero or tiny time steps,
+    /// e.g. for physics integrators that divide by `dt`.
+    fn min_dt(mut self, dt: f64) -> Self {
+        self.set_min_dt(dt);
+        self
+    }
+
+    /// The maximum `dt` of variable updates in seconds.
+    ///
+    /// This avoids huge time steps after a stall.
+    fn set_max_dt(&mut self, dt: f64) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings {
+            max_dt: dt,
+            ..old_settings
+        })
+    }
+
+    /// The maximum `dt` of variable updates in seconds.
+    ///
+    /// This avoids huge time steps after a stall.
+    fn max_dt(mut self, dt: f64) -> Self {
+        self.set_max_dt(dt);
+        self
+    }
+
+    /// The number of times per second to poll input while waiting.
+    ///
+    /// When set to `0`, input is only polled when the event loop wakes up.
+    fn set_input_rate(&mut self, frequency: u64) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings {
+            input_rate: frequency,
+            ..old_settings
+        })
+    }
+
+    /// The number of times per second to poll input while waiting.
+    ///
+    /// When set to `0`, input is only polled when the event loop wakes up.
+    fn input_rate(mut self, frequency: u64) -> Self {
+        self.set_input_rate(frequency);
+        self
+    }
+
+    /// The number of slow updates per second.
+    ///
+    /// When set to `0`, slow update events are disabled.
+    fn set_slow_ups(&mut self, frequency: u64) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings {
+            slow_ups: frequency,
+            ..old_settings
+        })
+    }
+
+    /// The number of slow updates per second.
+    ///
+    /// When set to `0`, slow update events are disabled.
+    fn slow_ups(mut self, frequency: u64) -> Self {
+        self.set_slow_ups(frequency);
+        self
+    }
+
+    /// Enable or disable emitting the focus state of the window as the first event.
+    ///
+    /// By default, no focus event is emitted until the focus changes.
+    fn set_initial_focus(&mut self, enable: bool) {
+        let old_settings = self.get_event_settings();
+        self.set_event_settings(EventSettings {
+            initial_focus: enable,
+            ..old_settings
+        })
+    }
+
+    /// Enable or disable emitting the focus state of the window as the first event.
+    ///
+    /// By default, no focus event is emitted until the focus changes.
+    fn initial_focus(mut self, enable: bool) -> Self {
+        self.set_initial_focus(enable);
+        self
+    }
 }
 
 impl EventLoop for EventSettings {
@@ -526,7 +1735,999 @@ impl EventLoop for Events {
         self.settings
     }
     fn set_event_settings(&mut self, settings: EventSettings) {
-        // Reset event loop to initial state.
+        // Reset event loop to initial state,
+        // but do not emit the initial focus event twice.
+        let first_event = self.first_event;
         *self = Events::new(settings);
+        self.first_event = first_event;
+    }
+}
+
+/// A source of events, implemented by `Events` and the adapters wrapping it.
+///
+/// Adapters are generic over their event source,
+/// so they can wrap each other, e.g. `events.coalesce().prioritize()`.
+pub trait EventSource: EventLoop {
+    /// Returns the next event.
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window;
+
+    /// Returns the event loop at the bottom of the adapters,
+    /// e.g. to change runtime options.
+    fn events(&self) -> &Events;
+
+    /// Returns the event loop at the bottom of the adapters mutably.
+    fn events_mut(&mut self) -> &mut Events;
+
+    /// Calls a closure on each event before it is returned.
+    ///
+    /// This is similar to `Iterator::inspect`.
+    fn inspect<F>(self, f: F) -> Inspect<F, Self>
+    where
+        F: FnMut(&Event),
+    {
+        Inspect::new(self, f)
+    }
+
+    /// Calls a closure with the lag in seconds
+    /// when the event loop can not keep up with the update rate.
+    fn on_lag<F>(self, f: F) -> OnLag<F, Self>
+    where
+        F: FnMut(f64),
+    {
+        OnLag::new(self, f)
+    }
+
+    /// Emits input events in a stable order,
+    /// see [`EVENT_PRIORITY`](constant.EVENT_PRIORITY.html).
+    fn prioritize(self) -> Prioritize<Self> {
+        Prioritize::new(self)
+    }
+
+    /// Merges input events that are ready at the same time,
+    /// see [`Coalesce`](struct.Coalesce.html) for the rules to enable.
+    fn coalesce(self) -> Coalesce<Self> {
+        Coalesce::new(self)
+    }
+
+    /// Plays back a timeline of events with real timing.
+    fn timeline(self, timeline: Vec<(f64, Event)>) -> TimelineEvents<Self> {
+        TimelineEvents::new(self, timeline)
+    }
+}
+
+impl EventSource for Events {
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        Events::next(self, window)
+    }
+
+    fn events(&self) -> &Events {
+        self
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        self
+    }
+}
+
+/// Returns the input events that are ready at the same time,
+/// followed by the next event that is not input, if any.
+///
+/// This is used by adapters that reorder or merge input events.
+fn next_input_batch<S, W>(events: &mut S, window: &mut W) -> (Vec<Event>, Option<Event>)
+where
+    S: EventSource,
+    W: Window,
+{
+    let mut batch = vec![];
+    while let Some(e) = events.next(window) {
+        if !e.is_input() {
+            return (batch, Some(e));
+        }
+        batch.push(e);
+    }
+    (batch, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use window::Size;
+
+    use super::*;
+
+    pub mod clock {
+        //! A manual clock that advances only when the event loop sleeps.
+
+        use std::{
+            cell::Cell,
+            time::{Duration, Instant},
+        };
+
+        thread_local! {
+            static NOW: Cell<Instant> = Cell::new(Instant::now());
+        }
+
+        pub fn now() -> Instant {
+            NOW.with(|t| t.get())
+        }
+
+        pub fn sleep(dur: Duration) {
+            NOW.with(|t| t.set(t.get() + dur));
+        }
+    }
+
+    /// A window that emits scripted input events.
+    pub struct TestWindow {
+        pub should_close: bool,
+        pub size: Size,
+        pub events: VecDeque<Event>,
+        /// Events that arrive when the clock reaches their time.
+        pub scheduled: VecDeque<(Instant, Event)>,
+        pub swaps: usize,
+        pub minimized: bool,
+        pub focused: bool,
+        pub scale_factor: f64,
+        pub refresh_rate: Option<f64>,
+    }
+
+    impl TestWindow {
+        pub fn new() -> TestWindow {
+            TestWindow {
+                should_close: false,
+                size: [800, 600].into(),
+                events: VecDeque::new(),
+                scheduled: VecDeque::new(),
+                swaps: 0,
+                minimized: false,
+                focused: true,
+                scale_factor: 1.0,
+                refresh_rate: None,
+            }
+        }
+
+        fn pop_event(&mut self) -> Option<Event> {
+            while let Some(&(time, _)) = self.scheduled.front() {
+                if time > clock::now() {
+                    break;
+                }
+                let (_, e) = self.scheduled.pop_front().unwrap();
+                self.events.push_back(e);
+            }
+            self.events.pop_front()
+        }
+    }
+
+    impl Window for TestWindow {
+        fn set_should_close(&mut self, value: bool) {
+            self.should_close = value;
+        }
+
+        fn should_close(&self) -> bool {
+            self.should_close
+        }
+
+        fn size(&self) -> Size {
+            self.size
+        }
+
+        fn swap_buffers(&mut self) {
+            self.swaps += 1;
+        }
+
+        fn wait_event(&mut self) -> Event {
+            self.events.pop_front().expect("No more scripted events")
+        }
+
+        fn wait_event_timeout(&mut self, timeout: Duration) -> Option<Event> {
+            let ev = self.pop_event();
+            if ev.is_none() {
+                clock::sleep(timeout);
+            }
+            ev
+        }
+
+        fn poll_event(&mut self) -> Option<Event> {
+            self.pop_event()
+        }
+
+        fn draw_size(&self) -> Size {
+            self.size
+        }
+
+        fn is_minimized(&self) -> bool {
+            self.minimized
+        }
+
+        fn is_focused(&self) -> bool {
+            self.focused
+        }
+
+        fn scale_factor(&self) -> f64 {
+            self.scale_factor
+        }
+
+        fn refresh_rate(&self) -> Option<f64> {
+            self.refresh_rate
+        }
+    }
+
+    #[test]
+    fn test_reuse_event_settings() {
+        let settings = EventSettings::new()
+            .ups(60)
+            .max_fps(30)
+            .bench_mode(true)
+            .swap_buffers(false);
+        let copy = settings;
+        assert_eq!(settings, copy);
+
+        let a = Events::new(settings);
+        let b = Events::new(copy);
+        assert_eq!(a.get_event_settings(), b.get_event_settings());
+        assert_eq!(a.get_event_settings().ups, 60);
+        assert_eq!(a.get_event_settings().max_fps, 30);
+        assert!(a.get_event_settings().bench_mode);
+        assert!(!a.get_event_settings().swap_buffers);
+    }
+
+    #[test]
+    fn test_runtime_options() {
+        let options = RuntimeOptions::new()
+            .invert_scroll(false, true)
+            .render_scale(0.5)
+            .event_enabled(event_id::MOUSE_RELATIVE, false);
+        let settings = EventSettings {
+            options,
+            ..EventSettings::new()
+        };
+        let mut events = Events::new(settings);
+        assert_eq!(events.get_options(), options);
+        assert_eq!(events.get_render_scale(), 0.5);
+        assert!(!events.get_event_enabled(event_id::MOUSE_RELATIVE));
+
+        // Options are kept when changing other settings.
+        events.set_clamp_cursor(true);
+        events.set_ups(30);
+        assert_eq!(events.get_options(), options.clamp_cursor(true));
+        assert_eq!(events.get_event_settings().options, events.get_options());
+    }
+
+    #[test]
+    fn test_event_settings_presets() {
+        let performance = EventSettings::performance();
+        assert_eq!(performance.max_fps, 144);
+        assert_eq!(performance.ups, 60);
+
+        let balanced = EventSettings::balanced();
+        assert_eq!(balanced, EventSettings::new());
+        assert_eq!(balanced.max_fps, 60);
+        assert_eq!(balanced.ups, 120);
+
+        let quality = EventSettings::quality();
+        assert_eq!(quality.max_fps, 60);
+        assert_eq!(quality.ups, 240);
+
+        for preset in &[performance, balanced, quality] {
+            assert_eq!(preset.ups_reset, DEFAULT_UPS_RESET);
+            assert!(preset.swap_buffers);
+            assert!(!preset.bench_mode);
+            assert!(!preset.lazy);
+        }
+    }
+
+    #[test]
+    fn test_disable_rendering() {
+        use input::{RenderEvent, UpdateEvent};
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new());
+        events.set_rendering(false);
+        let (mut updates, mut renders) = (0, 0);
+        for _ in 0..100 {
+            let e = events.next(&mut window).unwrap();
+            if e.update_args().is_some() {
+                updates += 1;
+            }
+            if e.render_args().is_some() {
+                renders += 1;
+            }
+        }
+        assert!(updates > 0);
+        assert_eq!(renders, 0);
+        assert_eq!(window.swaps, 0);
+
+        events.set_rendering(true);
+        let mut rendered = false;
+        for _ in 0..100 {
+            if events.next(&mut window).unwrap().render_args().is_some() {
+                rendered = true;
+                break;
+            }
+        }
+        assert!(rendered);
+    }
+
+    #[test]
+    fn test_max_fps() {
+        use input::RenderEvent;
+
+        // Returns the time between the first two render events.
+        fn frame_time(settings: EventSettings, refresh_rate: Option<f64>) -> Duration {
+            let mut window = TestWindow::new();
+            window.refresh_rate = refresh_rate;
+            let mut events = Events::new(settings);
+            let mut renders = vec![];
+            while renders.len() < 2 {
+                if events.next(&mut window).unwrap().render_args().is_some() {
+                    renders.push(clock::now());
+                }
+            }
+            renders[1] - renders[0]
+        }
+
+        let frame = |fps: u64| Duration::from_nanos(BILLION / fps);
+        let settings = EventSettings::new();
+        assert_eq!(frame_time(settings.max_fps(30), None), frame(30));
+        // Unlimited frame rate never waits.
+        assert_eq!(
+            frame_time(settings.max_fps(0), None),
+            Duration::from_secs(0)
+        );
+
+        let display = settings.max_fps(30).sync_to_display(true);
+        assert_eq!(frame_time(display, Some(144.0)), frame(144));
+        // Falls back to `max_fps` without a valid refresh rate.
+        for &rate in &[
+            None,
+            Some(0.0),
+            Some(-60.0),
+            Some(f64::NAN),
+            Some(f64::INFINITY),
+        ] {
+            assert_eq!(frame_time(display, rate), frame(30));
+        }
+    }
+
+    #[test]
+    fn test_unlimited_variable_updates() {
+        use input::UpdateEvent;
+
+        // Variable updates follow an unlimited frame rate, without waiting.
+        let mut window = TestWindow::new();
+        let settings = EventSettings::new().max_fps(0).variable_updates(true);
+        let mut events = Events::new(settings);
+        let start = clock::now();
+        let updates = (0..100)
+            .filter(|_| events.next(&mut window).unwrap().update_args().is_some())
+            .count();
+        assert!(updates >= 30);
+        assert_eq!(clock::now(), start);
+    }
+
+    #[test]
+    fn test_minimized_window() {
+        use input::RenderEvent;
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new());
+        window.minimized = true;
+        for _ in 0..100 {
+            assert!(events.next(&mut window).unwrap().render_args().is_none());
+        }
+        assert_eq!(window.swaps, 0);
+
+        window.minimized = false;
+        let rendered = (0..100).any(|_| events.next(&mut window).unwrap().render_args().is_some());
+        assert!(rendered);
+    }
+
+    #[test]
+    fn test_initial_focus() {
+        use input::FocusEvent;
+
+        let mut window = TestWindow::new();
+        window.focused = false;
+        let mut events = Events::new(EventSettings::new().initial_focus(true));
+        assert_eq!(events.next(&mut window).unwrap().focus_args(), Some(false));
+        let focused = (0..100).any(|_| events.next(&mut window).unwrap().focus_args().is_some());
+        assert!(!focused);
+
+        // No focus event is emitted by default.
+        let mut events = Events::new(EventSettings::new());
+        let focused = (0..100).any(|_| events.next(&mut window).unwrap().focus_args().is_some());
+        assert!(!focused);
+
+        // The initial focus event is dropped when focus events are disabled.
+        let mut events = Events::new(EventSettings::new().initial_focus(true));
+        events.set_event_enabled(event_id::FOCUS, false);
+        let focused = (0..100).any(|_| events.next(&mut window).unwrap().focus_args().is_some());
+        assert!(!focused);
+    }
+
+    #[test]
+    fn test_variable_updates() {
+        use input::{RenderEvent, UpdateEvent};
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new().variable_updates(true));
+        let mut last_update = now();
+        let mut dts = vec![];
+        let mut frame = 0;
+        while dts.len() < 4 {
+            let e = events.next(&mut window).unwrap();
+            if e.render_args().is_some() {
+                // Simulate uneven time spent rendering.
+                frame += 1;
+                clock::sleep(Duration::from_millis(10 * frame));
+            }
+            if let Some(args) = e.update_args() {
+                let current_time = now();
+                assert_eq!(args.dt, duration_to_secs(current_time - last_update));
+                last_update = current_time;
+                dts.push(args.dt);
+            }
+        }
+        // One update per frame, with uneven time steps.
+        assert_eq!(frame, 4);
+        assert!(dts.windows(2).all(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    fn test_variable_updates_clamp() {
+        use input::{RenderEvent, UpdateEvent};
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new().variable_updates(true));
+        let mut dts = vec![];
+        let mut stalled = false;
+        while dts.len() < 2 {
+            let e = events.next(&mut window).unwrap();
+            if e.render_args().is_some() && !dts.is_empty() && !stalled {
+                stalled = true;
+                clock::sleep(Duration::from_secs(1));
+            }
+            if let Some(args) = e.update_args() {
+                dts.push(args.dt);
+            }
+        }
+        // The first frame takes no time.
+        assert_eq!(dts, vec![DEFAULT_MIN_DT, DEFAULT_MAX_DT]);
+    }
+
+    #[test]
+    fn test_borrowed_no_window() {
+        use input::UpdateEvent;
+        use window::{NoWindow, WindowSettings};
+
+        let mut window = NoWindow::new(&WindowSettings::new("test", [640, 480]));
+        let mut events = Events::new(EventSettings::new());
+        let mut updates = 0;
+        while let Some(e) = events.next(&mut window) {
+            if e.update_args().is_some() {
+                updates += 1;
+                if updates == 10 {
+                    window.set_should_close(true);
+                }
+            }
+        }
+        assert_eq!(updates, 10);
+        assert!(window.should_close());
+    }
+
+    #[test]
+    fn test_no_window_close_after() {
+        use window::{NoWindow, WindowSettings};
+
+        let mut window = NoWindow::new(&WindowSettings::new("test", [640, 480])).close_after(3);
+        let mut events = Events::new(EventSettings::new());
+        let mut ids = vec![];
+        while let Some(e) = events.next(&mut window) {
+            ids.push(e.event_id());
+        }
+        // The loop ends after three iterations, when the window is polled the third time.
+        assert_eq!(
+            ids,
+            vec![event_id::RENDER, event_id::AFTER_RENDER, event_id::IDLE]
+        );
+        assert!(window.should_close());
+    }
+
+    #[test]
+    fn test_fold_events() {
+        use input::RenderEvent;
+        use window::{NoWindow, WindowSettings};
+
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings).close_after(20);
+        let renders = Events::new(EventSettings::new())
+            .fold_events(&mut window, 0, |n, e| n + e.render_args().map_or(0, |_| 1));
+
+        let mut window = NoWindow::new(&settings).close_after(20);
+        let mut events = Events::new(EventSettings::new());
+        let mut expected = 0;
+        while let Some(e) = events.next(&mut window) {
+            if e.render_args().is_some() {
+                expected += 1;
+            }
+        }
+        assert!(renders > 0);
+        assert_eq!(renders, expected);
+    }
+
+    #[test]
+    fn test_frame_budget() {
+        use input::{RenderEvent, UpdateEvent};
+
+        // Returns the number of updates before the first render after a stalled update.
+        fn updates_after_stall(budget: Option<f64>) -> usize {
+            let mut window = TestWindow::new();
+            let mut events = Events::new(EventSettings::new());
+            events.set_frame_budget(budget);
+            while events.next(&mut window).unwrap().update_args().is_none() {}
+            // An update takes longer than the frame budget.
+            clock::sleep(Duration::from_millis(30));
+            let mut updates = 0;
+            loop {
+                let e = events.next(&mut window).unwrap();
+                if e.render_args().is_some() {
+                    return updates;
+                }
+                if e.update_args().is_some() {
+                    updates += 1;
+                }
+            }
+        }
+
+        // Without a budget, the late frame is rendered right away.
+        assert_eq!(updates_after_stall(None), 0);
+        // With a budget, the late frame is skipped and rendering resumes next frame.
+        assert!(updates_after_stall(Some(0.005)) > 0);
+    }
+
+    #[test]
+    fn test_max_frame_time() {
+        use input::{RenderEvent, UpdateEvent};
+
+        // Returns the extrapolation time and number of updates
+        // before the first render after a stall.
+        fn render_after_stall(max_frame_time: Option<f64>) -> (f64, usize) {
+            let mut window = TestWindow::new();
+            let mut events = Events::new(EventSettings::new().ups_reset(0));
+            events.set_max_frame_time(max_frame_time);
+            while events.next(&mut window).unwrap().update_args().is_none() {}
+            clock::sleep(Duration::from_secs(1));
+            let mut updates = 0;
+            loop {
+                let e = events.next(&mut window).unwrap();
+                if let Some(args) = e.render_args() {
+                    return (args.ext_dt, updates);
+                }
+                if e.update_args().is_some() {
+                    updates += 1;
+                }
+            }
+        }
+
+        let (ext_dt, _) = render_after_stall(None);
+        assert!(ext_dt > 0.9);
+        let (ext_dt, updates) = render_after_stall(Some(0.1));
+        assert!(ext_dt <= 0.1);
+        assert!(updates <= 12);
+    }
+
+    #[test]
+    fn test_invert_scroll() {
+        use input::Motion;
+
+        let mut window = TestWindow::new();
+        window
+            .events
+            .push_back(Motion::MouseScroll([0.5, 1.0]).into());
+        let mut events = Events::new(EventSettings::new());
+        events.set_invert_scroll(false, true);
+        let scroll = (0..100)
+            .find_map(|_| events.next(&mut window).unwrap().mouse_scroll_args())
+            .unwrap();
+        assert_eq!(scroll, [0.5, -1.0]);
+    }
+
+    #[test]
+    fn test_time_until_next() {
+        use input::AfterRenderEvent;
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new());
+        while events
+            .next(&mut window)
+            .unwrap()
+            .after_render_args()
+            .is_none()
+        {}
+        let t0 = events.time_until_next();
+        assert!(t0 > 0.0 && t0 <= 1.0 / 60.0);
+        clock::sleep(Duration::from_millis(2));
+        let t1 = events.time_until_next();
+        assert!((t0 - t1 - 0.002).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_poll_event() {
+        use input::{AfterRenderEvent, RenderEvent};
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new().ups(30));
+        while events
+            .next(&mut window)
+            .unwrap()
+            .after_render_args()
+            .is_none()
+        {}
+        assert_eq!(events.poll_event(&mut window), Poll::Pending);
+        clock::sleep(Duration::from_secs_f64(events.time_until_next()));
+        match events.poll_event(&mut window) {
+            Poll::Ready(Some(e)) => assert!(e.render_args().is_some()),
+            x => panic!("expected render event, got {:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_poll_event_slow_ups() {
+        use input::{SlowUpdateEvent, UpdateEvent};
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new().ups(120).slow_ups(60));
+        let mut updates = 0;
+        let mut slow_updates = 0;
+        // Every second update completes a slow time step.
+        let mut slow_update_due = false;
+        while updates < 4 {
+            let e = match events.poll_event(&mut window) {
+                Poll::Ready(e) => e.unwrap(),
+                Poll::Pending => {
+                    assert!(!slow_update_due, "slow update is pending");
+                    clock::sleep(Duration::from_secs_f64(events.time_until_next()));
+                    continue;
+                }
+            };
+            assert_eq!(e.slow_update_args().is_some(), slow_update_due);
+            if e.slow_update_args().is_some() {
+                slow_updates += 1;
+            }
+            slow_update_due = false;
+            if e.update_args().is_some() {
+                updates += 1;
+                slow_update_due = updates % 2 == 0;
+            }
+        }
+        assert_eq!(slow_updates, 1);
+        match events.poll_event(&mut window) {
+            Poll::Ready(Some(e)) => assert!(e.slow_update_args().is_some()),
+            x => panic!("expected slow update event, got {:?}", x),
+        }
+    }
+
+    #[test]
+    fn test_poll_event_lazy() {
+        use input::{AfterRenderEvent, RenderEvent, TextEvent};
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new().lazy(true));
+        while events
+            .next(&mut window)
+            .unwrap()
+            .after_render_args()
+            .is_none()
+        {}
+        assert_eq!(events.poll_event(&mut window), Poll::Pending);
+
+        // Input wakes up the event loop, which then renders.
+        window.events.push_back(Input::Text("a".into()).into());
+        let mut next = || match events.poll_event(&mut window) {
+            Poll::Ready(Some(e)) => e,
+            x => panic!("expected event, got {:?}", x),
+        };
+        assert_eq!(next().text_args().as_deref(), Some("a"));
+        assert!(next().render_args().is_some());
+        assert!(next().after_render_args().is_some());
+        assert_eq!(events.poll_event(&mut window), Poll::Pending);
+    }
+
+    #[test]
+    fn test_render_scale() {
+        use input::RenderEvent;
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new());
+        events.set_render_scale(0.5);
+        let args = (0..100)
+            .find_map(|_| events.next(&mut window).unwrap().render_args())
+            .unwrap();
+        assert_eq!(args.window_size, [800.0, 600.0]);
+        assert_eq!(args.draw_size, [400, 300]);
+    }
+
+    #[test]
+    fn test_input_rate() {
+        use input::{IdleEvent, TextEvent};
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new().max_fps(1).ups(1).input_rate(100));
+        while events.next(&mut window).unwrap().idle_args().is_none() {}
+        // Input arrives while the event loop waits for the next frame.
+        let idle_time = clock::now();
+        for text in &["a", "b", "c"] {
+            window.scheduled.push_back((
+                idle_time + Duration::from_millis(5),
+                Input::Text(text.to_string()).into(),
+            ));
+        }
+        let texts: Vec<_> = (0..3)
+            .map(|_| events.next(&mut window).unwrap().text_args().unwrap())
+            .collect();
+        assert_eq!(texts, vec!["a", "b", "c"]);
+        // Input is polled before the next frame.
+        assert!(clock::now() - idle_time <= Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_flush_inputs() {
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new());
+        for text in &["a", "b", "c"] {
+            window
+                .events
+                .push_back(Input::Text(text.to_string()).into());
+        }
+        events.flush_inputs(&mut window);
+        let e = events.next(&mut window).unwrap();
+        assert!(e.is_render() || e.is_update());
+    }
+
+    #[test]
+    fn test_input_before_update() {
+        use input::{AfterRenderEvent, TextEvent, UpdateEvent};
+
+        // Returns whether text input arriving with an update is emitted first.
+        fn text_first(input_before_update: bool) -> bool {
+            let mut window = TestWindow::new();
+            let mut events = Events::new(EventSettings::new());
+            events.set_input_before_update(input_before_update);
+            while events
+                .next(&mut window)
+                .unwrap()
+                .after_render_args()
+                .is_none()
+            {}
+            let next_update = clock::now() + Duration::from_secs_f64(events.time_until_next());
+            window
+                .scheduled
+                .push_back((next_update, Input::Text("a".into()).into()));
+            loop {
+                let e = events.next(&mut window).unwrap();
+                if e.text_args().is_some() {
+                    return true;
+                }
+                if e.update_args().is_some() {
+                    return false;
+                }
+            }
+        }
+
+        assert!(text_first(true));
+        assert!(!text_first(false));
+    }
+
+    #[test]
+    fn test_last_event_time() {
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new());
+        let mut last = events.get_last_event_time();
+        for _ in 0..10 {
+            events.next(&mut window).unwrap();
+            let time = events.get_last_event_time();
+            assert!(time >= last);
+            last = time;
+        }
+        assert_eq!(last, clock::now());
+    }
+
+    #[test]
+    fn test_clamp_cursor() {
+        use input::{Motion, MouseRelativeEvent};
+
+        let mut window = TestWindow::new();
+        window.size = [100, 100].into();
+        window
+            .events
+            .push_back(Motion::MouseCursor([150.0, -10.0]).into());
+        window
+            .events
+            .push_back(Motion::MouseRelative([150.0, -10.0]).into());
+        let mut events = Events::new(EventSettings::new());
+        events.set_clamp_cursor(true);
+        let cursor = (0..100)
+            .find_map(|_| events.next(&mut window).unwrap().mouse_cursor_args())
+            .unwrap();
+        assert_eq!(cursor, [100.0, 0.0]);
+        let relative = (0..100)
+            .find_map(|_| events.next(&mut window).unwrap().mouse_relative_args())
+            .unwrap();
+        assert_eq!(relative, [150.0, -10.0]);
+    }
+
+    #[test]
+    fn test_logical_cursor() {
+        use input::Motion;
+
+        let mut window = TestWindow::new();
+        window.scale_factor = 2.0;
+        window
+            .events
+            .push_back(Motion::MouseCursor([400.0, 400.0]).into());
+        window
+            .events
+            .push_back(Motion::MouseCursor([400.0, 400.0]).into());
+        let mut events = Events::new(EventSettings::new());
+        let mut next_cursor = |events: &mut Events| {
+            (0..100)
+                .find_map(|_| events.next(&mut window).unwrap().mouse_cursor_args())
+                .unwrap()
+        };
+        assert_eq!(next_cursor(&mut events), [400.0, 400.0]);
+        events.set_logical_cursor(true);
+        assert_eq!(next_cursor(&mut events), [200.0, 200.0]);
+    }
+
+    #[test]
+    fn test_scroll_threshold() {
+        use input::Motion;
+
+        let mut window = TestWindow::new();
+        window
+            .events
+            .push_back(Motion::MouseScroll([0.0, 0.01]).into());
+        window
+            .events
+            .push_back(Motion::MouseScroll([0.01, 0.2]).into());
+        let mut events = Events::new(EventSettings::new());
+        events.set_scroll_threshold(0.1);
+        let scrolls: Vec<_> = (0..100)
+            .filter_map(|_| events.next(&mut window).unwrap().mouse_scroll_args())
+            .collect();
+        assert_eq!(scrolls, vec![[0.0, 0.2]]);
+    }
+
+    #[test]
+    fn test_suppress_touch_mouse() {
+        use input::{Motion, Touch, TouchArgs};
+
+        let mut window = TestWindow::new();
+        let touch: Event = Input::Move(Motion::Touch(TouchArgs::new(
+            0,
+            0,
+            [0.5, 0.5],
+            1.0,
+            Touch::Start,
+        )))
+        .into();
+        window.events.push_back(touch.clone());
+        window
+            .events
+            .push_back(Motion::MouseCursor([400.0, 300.0]).into());
+        let mut events = Events::new(EventSettings::new());
+        events.set_suppress_touch_mouse(Some(0.5));
+        let inputs: Vec<_> = (0..100)
+            .map(|_| events.next(&mut window).unwrap())
+            .filter(|e| e.touch_args().is_some() || e.mouse_cursor_args().is_some())
+            .collect();
+        assert_eq!(inputs, vec![touch]);
+
+        // Mouse events pass through after the timeout.
+        clock::sleep(Duration::from_secs(1));
+        window
+            .events
+            .push_back(Motion::MouseCursor([400.0, 300.0]).into());
+        let cursor = (0..100).any(|_| {
+            events
+                .next(&mut window)
+                .unwrap()
+                .mouse_cursor_args()
+                .is_some()
+        });
+        assert!(cursor);
+    }
+
+    #[test]
+    fn test_center_cursor() {
+        use window::{NoWindow, WindowSettings};
+
+        let mut window = NoWindow::new(&WindowSettings::new("test", [640, 480]));
+        let mut events = Events::new(EventSettings::new());
+        events.set_center_cursor(true);
+        events.next(&mut window);
+        assert_eq!(window.get_cursor_position(), [320.0, 240.0]);
+    }
+
+    #[test]
+    fn test_slow_ups() {
+        use input::{SlowUpdateEvent, UpdateEvent};
+
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new().ups(120).slow_ups(10));
+        let mut updates = 0;
+        let mut slow_updates = 0;
+        let mut previous_update = false;
+        // The slow update of the 120th update is emitted before the 121st update.
+        while updates <= 120 {
+            let e = events.next(&mut window).unwrap();
+            if let Some(args) = e.slow_update_args() {
+                // Slow updates directly follow an update.
+                assert!(previous_update);
+                assert!((args.dt - 0.1).abs() < 1e-9);
+                slow_updates += 1;
+            }
+            previous_update = e.update_args().is_some();
+            if previous_update {
+                updates += 1;
+            }
+        }
+        assert_eq!(slow_updates, 10);
+    }
+
+    #[test]
+    fn test_reset_timing() {
+        use input::{RenderEvent, UpdateEvent};
+
+        // Returns the number of updates emitted right after a pause,
+        // before the event loop waits again.
+        fn updates_after_pause(reset: bool) -> usize {
+            let mut window = TestWindow::new();
+            let mut events = Events::new(EventSettings::new().ups_reset(0));
+            while events.next(&mut window).unwrap().render_args().is_none() {}
+            clock::sleep(Duration::from_secs(1));
+            if reset {
+                events.reset_timing();
+            }
+            let resumed = clock::now();
+            let mut updates = 0;
+            while clock::now() == resumed {
+                if events.next(&mut window).unwrap().update_args().is_some() {
+                    updates += 1;
+                }
+            }
+            updates
+        }
+
+        assert!(updates_after_pause(false) > 100);
+        // The call that waits may return the next regular update.
+        assert!(updates_after_pause(true) <= 1);
+    }
+
+    #[test]
+    fn test_event_enabled() {
+        use input::{Motion, MouseRelativeEvent};
+
+        let mut window = TestWindow::new();
+        window
+            .events
+            .push_back(Motion::MouseRelative([1.0, 2.0]).into());
+        window
+            .events
+            .push_back(Motion::MouseCursor([3.0, 4.0]).into());
+        let mut events = Events::new(EventSettings::new());
+        events.set_event_enabled(event_id::MOUSE_RELATIVE, false);
+        assert!(!events.get_event_enabled(event_id::MOUSE_RELATIVE));
+        assert!(events.get_event_enabled(event_id::UPDATE));
+        let inputs: Vec<_> = (0..100)
+            .map(|_| events.next(&mut window).unwrap())
+            .filter(|e| e.mouse_relative_args().is_some() || e.mouse_cursor_args().is_some())
+            .collect();
+        assert_eq!(inputs, vec![Motion::MouseCursor([3.0, 4.0]).into()]);
     }
 }
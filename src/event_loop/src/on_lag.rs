@@ -0,0 +1,143 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use input::{Event, UpdateEvent};
+use window::Window;
+
+use crate::{now, EventLoop, EventSettings, EventSource, Events};
+
+/// The default minimum time in seconds between lag warnings.
+const DEFAULT_INTERVAL: f64 = 1.0;
+
+/// Calls a closure when the event loop can not keep up with the update rate,
+/// e.g. to log a warning about dropped frames.
+///
+/// The closure is called with the lag in seconds,
+/// when an update event is late by more than one update interval.
+/// Warnings are rate-limited to avoid flooding logs.
+///
+/// This is created by [`EventSource::on_lag()`](trait.EventSource.html#method.on_lag).
+pub struct OnLag<F, S = Events> {
+    events: S,
+    f: F,
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl<F, S> OnLag<F, S>
+where
+    F: FnMut(f64),
+    S: EventSource,
+{
+    /// Creates a new lag warning adapter.
+    pub fn new(events: S, f: F) -> OnLag<F, S> {
+        OnLag {
+            events,
+            f,
+            interval: Duration::from_secs_f64(DEFAULT_INTERVAL),
+            last: None,
+        }
+    }
+
+    /// Sets minimum interval in seconds between warnings.
+    pub fn set_interval(&mut self, interval: f64) {
+        self.interval = Duration::from_secs_f64(interval);
+    }
+
+    /// Sets minimum interval in seconds between warnings.
+    ///
+    /// This method moves the current adapter,
+    /// unlike [`set_interval()`](#method.set_interval),
+    /// so that it can be used in method chaining.
+    pub fn interval(mut self, interval: f64) -> Self {
+        self.set_interval(interval);
+        self
+    }
+}
+
+impl<F, S> fmt::Debug for OnLag<F, S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnLag")
+            .field("events", &self.events)
+            .field("interval", &self.interval)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+impl<F, S> EventSource for OnLag<F, S>
+where
+    F: FnMut(f64),
+    S: EventSource,
+{
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        let e = self.events.next(window)?;
+        if let Some(args) = e.update_args() {
+            let lag = self.events.events().get_lag();
+            if lag > args.dt {
+                let current_time = now();
+                match self.last {
+                    Some(last) if current_time - last < self.interval => {}
+                    _ => {
+                        self.last = Some(current_time);
+                        (self.f)(lag);
+                    }
+                }
+            }
+        }
+        Some(e)
+    }
+
+    fn events(&self) -> &Events {
+        self.events.events()
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        self.events.events_mut()
+    }
+}
+
+impl<F, S> EventLoop for OnLag<F, S>
+where
+    S: EventSource,
+{
+    fn get_event_settings(&self) -> EventSettings {
+        self.events.get_event_settings()
+    }
+    fn set_event_settings(&mut self, settings: EventSettings) {
+        self.events.set_event_settings(settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::RenderEvent;
+
+    use super::*;
+    use crate::tests::{clock, TestWindow};
+
+    #[test]
+    fn test_on_lag() {
+        let mut window = TestWindow::new();
+        let mut lags = vec![];
+        let mut events = Events::new(EventSettings::new()).on_lag(|lag| lags.push(lag));
+        let mut stalled = false;
+        for _ in 0..100 {
+            let e = events.next(&mut window).unwrap();
+            if e.render_args().is_some() && !stalled {
+                stalled = true;
+                clock::sleep(Duration::from_millis(100));
+            }
+        }
+        assert_eq!(lags.len(), 1);
+        assert!(lags[0] > 0.0);
+    }
+}
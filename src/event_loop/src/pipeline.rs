@@ -0,0 +1,144 @@
+use std::fmt;
+
+use input::Event;
+use window::Window;
+
+use crate::{EventLoop, EventSettings, EventSource, Events};
+
+/// A stage of an event pipeline.
+///
+/// Returns `None` to drop the event.
+pub type Stage = Box<dyn FnMut(Event) -> Option<Event>>;
+
+/// Applies a sequence of stages to the events of an event loop,
+/// e.g. to remap, filter or transform events.
+///
+/// Stages are applied in the order they were added.
+/// When a stage drops an event, the remaining stages are skipped
+/// and the pipeline moves on to the next event.
+pub struct EventPipeline<S = Events> {
+    events: S,
+    stages: Vec<Stage>,
+}
+
+impl<S> EventPipeline<S>
+where
+    S: EventSource,
+{
+    /// Creates a new pipeline without any stages.
+    pub fn new(events: S) -> EventPipeline<S> {
+        EventPipeline {
+            events,
+            stages: vec![],
+        }
+    }
+
+    /// Adds a stage at the end of the pipeline.
+    pub fn add_stage<F>(&mut self, f: F)
+    where
+        F: FnMut(Event) -> Option<Event> + 'static,
+    {
+        self.stages.push(Box::new(f));
+    }
+
+    /// Adds a stage at the end of the pipeline.
+    ///
+    /// This method moves the current pipeline,
+    /// unlike [`add_stage()`](#method.add_stage),
+    /// so that it can be used in method chaining.
+    pub fn stage<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(Event) -> Option<Event> + 'static,
+    {
+        self.add_stage(f);
+        self
+    }
+}
+
+impl<S> fmt::Debug for EventPipeline<S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventPipeline")
+            .field("events", &self.events)
+            .field("stages", &self.stages.len())
+            .finish()
+    }
+}
+
+impl<S> EventSource for EventPipeline<S>
+where
+    S: EventSource,
+{
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        'events: loop {
+            let mut e = self.events.next(window)?;
+            for stage in &mut self.stages {
+                match stage(e) {
+                    Some(x) => e = x,
+                    None => continue 'events,
+                }
+            }
+            return Some(e);
+        }
+    }
+
+    fn events(&self) -> &Events {
+        self.events.events()
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        self.events.events_mut()
+    }
+}
+
+impl<S> EventLoop for EventPipeline<S>
+where
+    S: EventSource,
+{
+    fn get_event_settings(&self) -> EventSettings {
+        self.events.get_event_settings()
+    }
+    fn set_event_settings(&mut self, settings: EventSettings) {
+        self.events.set_event_settings(settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{Button, ButtonArgs, ButtonState, Key, PressEvent};
+
+    use super::*;
+    use crate::tests::TestWindow;
+
+    #[test]
+    fn test_pipeline() {
+        let mut window = TestWindow::new();
+        for key in [Key::W, Key::Escape, Key::A] {
+            window
+                .events
+                .push_back(ButtonArgs::new(ButtonState::Press, key.into()).into());
+        }
+
+        let mut events = EventPipeline::new(Events::new(EventSettings::new()))
+            .stage(|e| match e.press_args() {
+                Some(Button::Keyboard(Key::W)) => PressEvent::from_button(Key::Up.into(), &e),
+                _ => Some(e),
+            })
+            .stage(|e| match e.press_args() {
+                Some(Button::Keyboard(Key::Escape)) => None,
+                _ => Some(e),
+            });
+        let pressed: Vec<_> = (0..10)
+            .filter_map(|_| events.next(&mut window).unwrap().press_args())
+            .collect();
+        assert_eq!(
+            pressed,
+            vec![Button::Keyboard(Key::Up), Button::Keyboard(Key::A)]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::VecDeque;
+
+use input::{Event, IdleArgs, Loop, RenderArgs, SlowUpdateArgs, UpdateArgs};
+
+/// Replays recorded events, e.g. for debugging a session.
+///
+/// The event player is an iterator over the recorded events.
+///
+/// Events can be recorded with [`EventSource::inspect()`](trait.EventSource.html#method.inspect).
+/// The playback speed scales the time between events,
+/// such that the `dt` of update, slow update and idle events and the `ext_dt` of render events
+/// are divided by the playback speed.
+/// The order of events is preserved.
+#[derive(Clone, Debug)]
+pub struct EventPlayer {
+    events: VecDeque<Event>,
+    playback_speed: f64,
+}
+
+impl EventPlayer {
+    /// Creates a new event player at normal playback speed.
+    pub fn new<I>(events: I) -> EventPlayer
+    where
+        I: IntoIterator<Item = Event>,
+    {
+        EventPlayer {
+            events: events.into_iter().collect(),
+            playback_speed: 1.0,
+        }
+    }
+
+    /// Sets the playback speed, e.g. `0.25` for slow motion or `4.0` for fast forward.
+    pub fn set_playback_speed(&mut self, speed: f64) {
+        self.playback_speed = speed;
+    }
+
+    /// Sets the playback speed.
+    ///
+    /// This method moves the current event player,
+    /// unlike [`set_playback_speed()`](#method.set_playback_speed),
+    /// so that it can be used in method chaining.
+    pub fn playback_speed(mut self, speed: f64) -> Self {
+        self.set_playback_speed(speed);
+        self
+    }
+
+    /// Returns the playback speed.
+    pub fn get_playback_speed(&self) -> f64 {
+        self.playback_speed
+    }
+}
+
+impl Iterator for EventPlayer {
+    type Item = Event;
+
+    /// Returns the next event, or `None` when the recording has ended.
+    fn next(&mut self) -> Option<Event> {
+        let e = self.events.pop_front()?;
+        let speed = self.playback_speed;
+        Some(match e {
+            Event::Loop(Loop::Update(args)) => UpdateArgs {
+                dt: args.dt / speed,
+            }
+            .into(),
+            Event::Loop(Loop::SlowUpdate(args)) => SlowUpdateArgs {
+                dt: args.dt / speed,
+            }
+            .into(),
+            Event::Loop(Loop::Idle(args)) => IdleArgs {
+                dt: args.dt / speed,
+            }
+            .into(),
+            Event::Loop(Loop::Render(args)) => RenderArgs {
+                ext_dt: args.ext_dt / speed,
+                ..args
+            }
+            .into(),
+            e => e,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{GenericEvent, Input, UpdateEvent};
+
+    use super::*;
+    use crate::{tests::TestWindow, EventSettings, EventSource, Events};
+
+    #[test]
+    fn test_player_speed() {
+        let mut window = TestWindow::new();
+        window.events.push_back(Input::Text("a".into()).into());
+        let mut recording = vec![];
+        {
+            let mut events =
+                Events::new(EventSettings::new()).inspect(|e| recording.push(e.clone()));
+            for _ in 0..20 {
+                events.next(&mut window);
+            }
+        }
+
+        let mut player = EventPlayer::new(recording.clone()).playback_speed(2.0);
+        for recorded in &recording {
+            let e = player.next().unwrap();
+            assert_eq!(e.event_id(), recorded.event_id());
+            if let Some(args) = recorded.update_args() {
+                assert_eq!(e.update_args().unwrap().dt, args.dt / 2.0);
+            }
+        }
+        assert_eq!(player.next(), None);
+    }
+}
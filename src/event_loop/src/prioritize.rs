@@ -0,0 +1,170 @@
+use std::collections::VecDeque;
+
+use input::{
+    event_id::{self, EventId},
+    Event, GenericEvent,
+};
+use window::Window;
+
+use crate::{next_input_batch, EventLoop, EventSettings, EventSource, Events};
+
+/// Input events that are emitted before other input events of the same frame,
+/// in this order.
+///
+/// The full order of events ready in one iteration of the event loop is:
+///
+/// 1. Input events listed here, e.g. resize
+/// 2. Other input events, in the order received from the window back-end
+/// 3. Update
+/// 4. Render, followed by after render
+///
+/// The event loop always handles input before updating and rendering,
+/// while [`Prioritize`](struct.Prioritize.html) sorts the input events.
+pub const EVENT_PRIORITY: [EventId; 2] = [event_id::RESIZE, event_id::SCALE_FACTOR_CHANGED];
+
+/// Emits input events in a stable order that does not depend on the window back-end,
+/// see [`EVENT_PRIORITY`](constant.EVENT_PRIORITY.html).
+///
+/// Input events that are ready at the same time are buffered and sorted,
+/// such that e.g. a resize is handled before the input events that followed it.
+///
+/// This is created by [`EventSource::prioritize()`](trait.EventSource.html#method.prioritize).
+#[derive(Clone, Debug)]
+pub struct Prioritize<S = Events> {
+    events: S,
+    queue: VecDeque<Event>,
+}
+
+impl<S> Prioritize<S>
+where
+    S: EventSource,
+{
+    /// Creates a new prioritize adapter.
+    pub fn new(events: S) -> Prioritize<S> {
+        Prioritize {
+            events,
+            queue: VecDeque::new(),
+        }
+    }
+}
+
+/// Returns the sort key of an input event.
+fn priority(id: EventId) -> usize {
+    EVENT_PRIORITY
+        .iter()
+        .position(|&x| x == id)
+        .unwrap_or(EVENT_PRIORITY.len())
+}
+
+impl<S> EventSource for Prioritize<S>
+where
+    S: EventSource,
+{
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        if let Some(e) = self.queue.pop_front() {
+            return Some(e);
+        }
+        let (mut batch, last) = next_input_batch(&mut self.events, window);
+        batch.sort_by_key(|e| priority(e.event_id()));
+        self.queue.extend(batch);
+        self.queue.extend(last);
+        self.queue.pop_front()
+    }
+
+    fn events(&self) -> &Events {
+        self.events.events()
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        self.events.events_mut()
+    }
+}
+
+impl<S> EventLoop for Prioritize<S>
+where
+    S: EventSource,
+{
+    fn get_event_settings(&self) -> EventSettings {
+        self.events.get_event_settings()
+    }
+    fn set_event_settings(&mut self, settings: EventSettings) {
+        self.events.set_event_settings(settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{
+        ButtonArgs, ButtonState, ControllerAxisArgs, ControllerAxisEvent, Input, Key, Motion,
+        RenderEvent, ResizeArgs,
+    };
+
+    use super::*;
+    use crate::tests::TestWindow;
+
+    #[test]
+    fn test_prioritize() {
+        let press: Event = ButtonArgs::new(ButtonState::Press, Key::A.into()).into();
+        let resize: Event = Input::Resize(ResizeArgs {
+            window_size: [400.0, 300.0],
+            draw_size: [400, 300],
+        })
+        .into();
+        let mut window = TestWindow::new();
+        window.events.push_back(press.clone());
+        window.events.push_back(resize.clone());
+
+        let mut events = Events::new(EventSettings::new()).prioritize();
+        let ids: Vec<_> = (0..3)
+            .map(|_| events.next(&mut window).unwrap().event_id())
+            .collect();
+        // Resize comes first, then other input in order, then render.
+        assert_eq!(
+            ids,
+            vec![event_id::RESIZE, event_id::BUTTON, event_id::RENDER]
+        );
+    }
+
+    #[test]
+    fn test_prioritize_coalesced() {
+        let mut window = TestWindow::new();
+        window
+            .events
+            .push_back(ButtonArgs::new(ButtonState::Press, Key::A.into()).into());
+        for &position in &[0.1, 0.2] {
+            window
+                .events
+                .push_back(Motion::ControllerAxis(ControllerAxisArgs::new(0, 0, position)).into());
+        }
+        window.events.push_back(
+            Input::Resize(ResizeArgs {
+                window_size: [400.0, 300.0],
+                draw_size: [400, 300],
+            })
+            .into(),
+        );
+
+        // Adapters can wrap each other.
+        let mut events = Events::new(EventSettings::new())
+            .coalesce()
+            .controller_axis(true)
+            .prioritize();
+        events.events_mut().set_render_scale(0.5);
+        let events: Vec<_> = (0..4).map(|_| events.next(&mut window).unwrap()).collect();
+        let ids: Vec<_> = events.iter().map(|e| e.event_id()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                event_id::RESIZE,
+                event_id::BUTTON,
+                event_id::CONTROLLER_AXIS,
+                event_id::RENDER
+            ]
+        );
+        assert_eq!(events[2].controller_axis_args().unwrap().position, 0.2);
+        assert_eq!(events[3].render_args().unwrap().draw_size, [400, 300]);
+    }
+}
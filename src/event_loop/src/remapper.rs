@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use input::{Button, ButtonEvent, Event};
+use window::Window;
+
+use crate::{EventLoop, EventSettings, EventSource, Events};
+
+/// Remaps buttons before events reach the application,
+/// e.g. to use Caps Lock as Escape.
+///
+/// Button events of remapped buttons are rewritten for both press and release.
+/// Other events and unmapped buttons pass unchanged.
+#[derive(Clone, Debug)]
+pub struct Remapper<S = Events> {
+    events: S,
+    map: HashMap<Button, Button>,
+}
+
+impl<S> Remapper<S>
+where
+    S: EventSource,
+{
+    /// Creates a new remapper without any remapped buttons.
+    pub fn new(events: S) -> Remapper<S> {
+        Remapper {
+            events,
+            map: HashMap::new(),
+        }
+    }
+
+    /// Remaps a button to another button.
+    pub fn set_remap(&mut self, from: Button, to: Button) {
+        self.map.insert(from, to);
+    }
+
+    /// Remaps a button to another button.
+    ///
+    /// This method moves the current remapper,
+    /// unlike [`set_remap()`](#method.set_remap),
+    /// so that it can be used in method chaining.
+    pub fn remap(mut self, from: Button, to: Button) -> Self {
+        self.set_remap(from, to);
+        self
+    }
+}
+
+impl<S> EventSource for Remapper<S>
+where
+    S: EventSource,
+{
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        let e = self.events.next(window)?;
+        if let Some(mut args) = e.button_args() {
+            if let Some(&button) = self.map.get(&args.button) {
+                args.button = button;
+                return ButtonEvent::from_button_args(args, &e);
+            }
+        }
+        Some(e)
+    }
+
+    fn events(&self) -> &Events {
+        self.events.events()
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        self.events.events_mut()
+    }
+}
+
+impl<S> EventLoop for Remapper<S>
+where
+    S: EventSource,
+{
+    fn get_event_settings(&self) -> EventSettings {
+        self.events.get_event_settings()
+    }
+    fn set_event_settings(&mut self, settings: EventSettings) {
+        self.events.set_event_settings(settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{ButtonArgs, ButtonState, Key, PressEvent};
+
+    use super::*;
+    use crate::tests::TestWindow;
+
+    #[test]
+    fn test_remap_caps_lock() {
+        let mut window = TestWindow::new();
+        window
+            .events
+            .push_back(ButtonArgs::new(ButtonState::Press, Key::CapsLock.into()).into());
+        let mut remapper = Remapper::new(Events::new(EventSettings::new()))
+            .remap(Key::CapsLock.into(), Key::Escape.into());
+        let pressed: Vec<_> = (0..10)
+            .filter_map(|_| remapper.next(&mut window).unwrap().press_args())
+            .collect();
+        assert_eq!(pressed, vec![Button::Keyboard(Key::Escape)]);
+    }
+}
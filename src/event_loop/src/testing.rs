@@ -0,0 +1,55 @@
+//! Helpers for testing applications with the event loop.
+//!
+//! This module requires the `testing` feature.
+
+use input::Event;
+use window::Window;
+
+use crate::Events;
+
+/// Returns the first event that matches a predicate.
+///
+/// Returns `None` if no event matches within the maximum number of iterations,
+/// or if the event loop ends.
+pub fn wait_for<W, F>(
+    events: &mut Events,
+    window: &mut W,
+    mut pred: F,
+    max_iters: usize,
+) -> Option<Event>
+where
+    W: Window,
+    F: FnMut(&Event) -> bool,
+{
+    for _ in 0..max_iters {
+        let e = events.next(window)?;
+        if pred(&e) {
+            return Some(e);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{event_id, GenericEvent, RenderEvent};
+    use window::{NoWindow, WindowSettings};
+
+    use super::*;
+    use crate::EventSettings;
+
+    #[test]
+    fn test_wait_for() {
+        let mut window = NoWindow::new(&WindowSettings::new("test", [640, 480]));
+        let mut events = Events::new(EventSettings::new());
+        let e = wait_for(&mut events, &mut window, |e| e.render_args().is_some(), 10);
+        assert_eq!(e.map(|e| e.event_id()), Some(event_id::RENDER));
+        let e = wait_for(
+            &mut events,
+            &mut window,
+            |e| e.event_id() == event_id::TEXT,
+            10,
+        );
+        assert_eq!(e, None);
+    }
+}
@@ -0,0 +1,165 @@
+use std::time::{Duration, Instant};
+
+use input::{
+    event_id::{self, EventId},
+    Event, GenericEvent, Motion, MouseRelativeEvent,
+};
+use window::Window;
+
+use crate::{now, EventLoop, EventSettings, EventSource, Events};
+
+#[derive(Copy, Clone, Debug)]
+struct Limit {
+    id: EventId,
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+/// Rate-limits noisy event types from an event loop.
+///
+/// Each throttled event id has a minimum interval between emitted events.
+/// Excess events within the interval are dropped,
+/// except mouse relative events, which are summed and emitted
+/// as a single event when the interval has passed, preserving the total motion.
+#[derive(Clone, Debug)]
+pub struct Throttle<S = Events> {
+    events: S,
+    limits: Vec<Limit>,
+    relative: Option<[f64; 2]>,
+}
+
+impl<S> Throttle<S>
+where
+    S: EventSource,
+{
+    /// Creates a new throttle without any limits.
+    pub fn new(events: S) -> Throttle<S> {
+        Throttle {
+            events,
+            limits: vec![],
+            relative: None,
+        }
+    }
+
+    /// Sets minimum interval in seconds between events of an event id.
+    pub fn set_limit(&mut self, id: EventId, interval: f64) {
+        let interval = Duration::from_secs_f64(interval);
+        if let Some(limit) = self.limits.iter_mut().find(|limit| limit.id == id) {
+            limit.interval = interval;
+        } else {
+            self.limits.push(Limit {
+                id,
+                interval,
+                last: None,
+            });
+        }
+    }
+
+    /// Sets minimum interval in seconds between events of an event id.
+    ///
+    /// This method moves the current throttle,
+    /// unlike [`set_limit()`](#method.set_limit),
+    /// so that it can be used in method chaining.
+    pub fn limit(mut self, id: EventId, interval: f64) -> Self {
+        self.set_limit(id, interval);
+        self
+    }
+
+    /// Returns true if an event can be emitted now, and starts a new interval.
+    fn ready(&mut self, id: EventId) -> bool {
+        let current_time = now();
+        match self.limits.iter_mut().find(|limit| limit.id == id) {
+            None => true,
+            Some(limit) => match limit.last {
+                Some(last) if current_time - last < limit.interval => false,
+                _ => {
+                    limit.last = Some(current_time);
+                    true
+                }
+            },
+        }
+    }
+}
+
+impl<S> EventSource for Throttle<S>
+where
+    S: EventSource,
+{
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        if let Some(pos) = self.relative {
+            if self.ready(event_id::MOUSE_RELATIVE) {
+                self.relative = None;
+                return Some(Motion::MouseRelative(pos).into());
+            }
+        }
+        loop {
+            let e = self.events.next(window)?;
+            let id = e.event_id();
+            if !self.limits.iter().any(|limit| limit.id == id) {
+                return Some(e);
+            }
+            let ready = self.ready(id);
+            if let Some(pos) = e.mouse_relative_args() {
+                let sum = match self.relative.take() {
+                    None => pos,
+                    Some(sum) => [sum[0] + pos[0], sum[1] + pos[1]],
+                };
+                if ready {
+                    return MouseRelativeEvent::from_pos(sum, &e);
+                }
+                self.relative = Some(sum);
+            } else if ready {
+                return Some(e);
+            }
+        }
+    }
+
+    fn events(&self) -> &Events {
+        self.events.events()
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        self.events.events_mut()
+    }
+}
+
+impl<S> EventLoop for Throttle<S>
+where
+    S: EventSource,
+{
+    fn get_event_settings(&self) -> EventSettings {
+        self.events.get_event_settings()
+    }
+    fn set_event_settings(&mut self, settings: EventSettings) {
+        self.events.set_event_settings(settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestWindow;
+
+    #[test]
+    fn test_throttle_mouse_relative() {
+        let mut window = TestWindow::new();
+        for _ in 0..10 {
+            window
+                .events
+                .push_back(Motion::MouseRelative([1.0, 2.0]).into());
+        }
+        let mut throttle =
+            Throttle::new(Events::new(EventSettings::new())).limit(event_id::MOUSE_RELATIVE, 0.05);
+        let mut emitted = vec![];
+        for _ in 0..100 {
+            if let Some(pos) = throttle.next(&mut window).unwrap().mouse_relative_args() {
+                emitted.push(pos);
+            }
+        }
+        // The first event passes, while the rest are summed into one.
+        assert_eq!(emitted, vec![[1.0, 2.0], [9.0, 18.0]]);
+    }
+}
@@ -0,0 +1,111 @@
+use std::{collections::VecDeque, time::Instant};
+
+use input::Event;
+use window::Window;
+
+use crate::{now, EventLoop, EventSettings, EventSource, Events};
+
+/// Plays back a timeline of events with real timing,
+/// e.g. for a scripted user interface demo.
+///
+/// Each event of the timeline has a time in seconds since the first call to `next`.
+/// It is emitted once the event loop clock reaches that time,
+/// interleaved with the events of the event loop.
+/// Events with the same time are emitted in the order of the timeline.
+///
+/// This is created by [`EventSource::timeline()`](trait.EventSource.html#method.timeline).
+#[derive(Clone, Debug)]
+pub struct TimelineEvents<S = Events> {
+    events: S,
+    timeline: VecDeque<(f64, Event)>,
+    start: Option<Instant>,
+}
+
+impl<S> TimelineEvents<S>
+where
+    S: EventSource,
+{
+    /// Creates a new timeline playback.
+    pub fn new(events: S, mut timeline: Vec<(f64, Event)>) -> TimelineEvents<S> {
+        timeline.sort_by(|a, b| a.0.total_cmp(&b.0));
+        TimelineEvents {
+            events,
+            timeline: timeline.into(),
+            start: None,
+        }
+    }
+
+    /// Returns true when all events of the timeline have been emitted.
+    pub fn is_finished(&self) -> bool {
+        self.timeline.is_empty()
+    }
+}
+
+impl<S> EventSource for TimelineEvents<S>
+where
+    S: EventSource,
+{
+    fn next<W>(&mut self, window: &mut W) -> Option<Event>
+    where
+        W: Window,
+    {
+        let start = *self.start.get_or_insert_with(now);
+        if let Some(&(time, _)) = self.timeline.front() {
+            if now().duration_since(start).as_secs_f64() >= time {
+                return self.timeline.pop_front().map(|(_, e)| e);
+            }
+        }
+        self.events.next(window)
+    }
+
+    fn events(&self) -> &Events {
+        self.events.events()
+    }
+
+    fn events_mut(&mut self) -> &mut Events {
+        self.events.events_mut()
+    }
+}
+
+impl<S> EventLoop for TimelineEvents<S>
+where
+    S: EventSource,
+{
+    fn get_event_settings(&self) -> EventSettings {
+        self.events.get_event_settings()
+    }
+    fn set_event_settings(&mut self, settings: EventSettings) {
+        self.events.set_event_settings(settings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use input::{Input, TextEvent};
+
+    use super::*;
+    use crate::tests::{clock, TestWindow};
+
+    #[test]
+    fn test_timeline() {
+        let mut window = TestWindow::new();
+        let mut events = Events::new(EventSettings::new()).timeline(vec![
+            (0.5, Input::Text("b".into()).into()),
+            (0.0, Input::Text("a".into()).into()),
+        ]);
+        let start = clock::now();
+        let mut texts = vec![];
+        while texts.len() < 2 {
+            let e = events.next(&mut window).unwrap();
+            if let Some(text) = e.text_args() {
+                texts.push((text, clock::now() - start));
+            }
+        }
+        assert_eq!(texts[0], ("a".into(), Duration::from_secs(0)));
+        assert_eq!(texts[1].0, "b");
+        assert!(texts[1].1 >= Duration::from_millis(500));
+        assert!(events.is_finished());
+    }
+}
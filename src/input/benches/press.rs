@@ -3,14 +3,22 @@
 extern crate input;
 extern crate test;
 
-use input::{Button, Input, Key, PressEvent};
+use input::{Button, ButtonArgs, ButtonState, Event, Key, PressEvent};
 use test::Bencher;
 
 #[bench]
 fn bench_input_press(bencher: &mut Bencher) {
-    let e = Input::Press(Button::Keyboard(Key::S));
+    let e: Event = ButtonArgs::new(ButtonState::Press, Button::Keyboard(Key::S)).into();
     let button = Button::Keyboard(Key::A);
     bencher.iter(|| {
-        let _: Option<Input> = PressEvent::from_button(button, &e);
+        let _: Option<Event> = PressEvent::from_button(button, &e);
+    });
+}
+
+#[bench]
+fn bench_input_press_direct(bencher: &mut Bencher) {
+    let button = Button::Keyboard(Key::A);
+    bencher.iter(|| {
+        let _: Event = ButtonArgs::new(ButtonState::Press, button).into();
     });
 }
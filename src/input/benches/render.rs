@@ -3,26 +3,35 @@
 extern crate input;
 extern crate test;
 
-use input::{Input, RenderArgs, RenderEvent};
+use input::{Event, RenderArgs, RenderEvent};
 use test::Bencher;
 
 #[bench]
 fn bench_input_render(bencher: &mut Bencher) {
-    let e = Input::Render(RenderArgs {
+    let e: Event = RenderArgs {
         ext_dt: 0.0,
-        width: 0,
-        height: 0,
-        draw_width: 0,
-        draw_height: 0,
+        window_size: [0.0, 0.0],
+        draw_size: [0, 0],
+    }
+    .into();
+    let args = RenderArgs {
+        ext_dt: 1.0,
+        window_size: [10.0, 10.0],
+        draw_size: [10, 10],
+    };
+    bencher.iter(|| {
+        let _: Option<Event> = RenderEvent::from_render_args(&args, &e);
     });
+}
+
+#[bench]
+fn bench_input_render_direct(bencher: &mut Bencher) {
     let args = RenderArgs {
         ext_dt: 1.0,
-        width: 10,
-        height: 10,
-        draw_width: 10,
-        draw_height: 10,
+        window_size: [10.0, 10.0],
+        draw_size: [10, 10],
     };
     bencher.iter(|| {
-        let _: Option<Input> = RenderEvent::from_render_args(&args, &e);
+        let _: Event = args.into();
     });
 }
@@ -0,0 +1,106 @@
+use crate::{Button, GenericEvent, PressEvent, ReleaseEvent};
+
+/// The default distance the axis must move back past the threshold to release.
+const DEFAULT_HYSTERESIS: f64 = 0.1;
+
+/// Converts a controller axis to a button,
+/// e.g. to use trigger pulls or stick directions for menu navigation.
+///
+/// The button is pressed when the axis reaches the threshold,
+/// and released when the axis moves back past the threshold by the hysteresis.
+/// The hysteresis avoids repeated presses when the axis is held near the threshold.
+///
+/// A negative threshold presses the button when the axis goes below it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AxisButton {
+    /// The controller id.
+    pub id: u32,
+    /// The controller axis.
+    pub axis: u8,
+    /// The button that is pressed and released.
+    pub button: Button,
+    /// The axis position at which the button is pressed.
+    pub threshold: f64,
+    /// The distance the axis must move back past the threshold to release the button.
+    pub hysteresis: f64,
+    pressed: bool,
+}
+
+impl AxisButton {
+    /// Creates a new axis button with default hysteresis.
+    pub fn new(id: u32, axis: u8, button: Button, threshold: f64) -> AxisButton {
+        AxisButton {
+            id,
+            axis,
+            button,
+            threshold,
+            hysteresis: DEFAULT_HYSTERESIS,
+            pressed: false,
+        }
+    }
+
+    /// Returns true if the button is pressed.
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns a press or release event of the button when the axis crosses the threshold.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<E> {
+        let args = e.controller_axis_args()?;
+        if args.id != self.id || args.axis != self.axis {
+            return None;
+        }
+        let position = args.position * self.threshold.signum();
+        let threshold = self.threshold.abs();
+        if !self.pressed && position >= threshold {
+            self.pressed = true;
+            PressEvent::from_button(self.button, e)
+        } else if self.pressed && position < threshold - self.hysteresis {
+            self.pressed = false;
+            ReleaseEvent::from_button(self.button, e)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ControllerAxisArgs, ControllerButton, Event};
+
+    #[test]
+    fn test_axis_button() {
+        let button = Button::Controller(ControllerButton::new(0, 7));
+        let mut axis_button = AxisButton::new(0, 5, button, 0.5);
+        let (mut presses, mut releases) = (0, 0);
+        let positions = (0..=9).chain((0..=9).rev()).map(|i| i as f64 * 0.1);
+        // Chatter near the threshold.
+        let positions = positions.chain(vec![0.5, 0.45, 0.5, 0.45, 0.0]);
+        for position in positions {
+            let e: Event = ControllerAxisArgs::new(0, 5, position).into();
+            if let Some(e) = axis_button.event(&e) {
+                if e.press_args() == Some(button) {
+                    presses += 1;
+                }
+                if e.release_args() == Some(button) {
+                    releases += 1;
+                }
+            }
+        }
+        assert_eq!((presses, releases), (2, 2));
+        assert!(!axis_button.is_pressed());
+    }
+
+    #[test]
+    fn test_axis_button_negative() {
+        let button = Button::Controller(ControllerButton::new(0, 0));
+        let mut axis_button = AxisButton::new(0, 1, button, -0.5);
+        let e: Event = ControllerAxisArgs::new(0, 1, 0.9).into();
+        assert_eq!(axis_button.event(&e), None);
+        let e: Event = ControllerAxisArgs::new(0, 1, -0.9).into();
+        assert_eq!(axis_button.event(&e).unwrap().press_args(), Some(button));
+    }
+}
@@ -19,13 +19,53 @@ pub struct ButtonArgs {
     /// An optional scancode that tells the physical layout of a keyboard key.
     /// For other devices than keyboard, this is set to `None`.
     ///
-    /// Scancode follows SDL (https://wiki.libsdl.org/SDL_Scancode).
+    /// Scancode follows SDL (<https://wiki.libsdl.org/SDL_Scancode>).
     ///
     /// This is stored here to make `Button` equality check work with keyboard layouts.
     ///
     /// Some window backends might not support scancodes.
-    /// To test a window backend, use https://github.com/PistonDevelopers/piston-examples/tree/master/user_input
+    /// To test a window backend, use <https://github.com/PistonDevelopers/piston-examples/tree/master/user_input>
     pub scancode: Option<i32>,
+    /// An optional raw key code from the window backend.
+    /// For other devices than keyboard, this is set to `None`.
+    ///
+    /// This preserves the original code of keys that map to `Key::Unknown`,
+    /// such that they can still be told apart, e.g. for key bindings.
+    #[serde(default)]
+    pub keycode: Option<u32>,
+    /// Whether a press is repeated by the operating system
+    /// or synthesized while the key is held down.
+    ///
+    /// This is `false` for the first press and for releases.
+    /// Window back-ends that can not tell repeats apart set this to `false`.
+    #[serde(default)]
+    pub repeat: bool,
+}
+
+impl ButtonArgs {
+    /// Creates button arguments without scancode or key code,
+    /// for a press that is not repeated or a release.
+    ///
+    /// Optional fields can be set with struct update syntax:
+    ///
+    /// ```
+    /// use input::{ButtonArgs, ButtonState, Key};
+    ///
+    /// let args = ButtonArgs {
+    ///     scancode: Some(4),
+    ///     ..ButtonArgs::new(ButtonState::Press, Key::A.into())
+    /// };
+    /// assert_eq!(args.keycode, None);
+    /// ```
+    pub fn new(state: ButtonState, button: Button) -> ButtonArgs {
+        ButtonArgs {
+            state,
+            button,
+            scancode: None,
+            keycode: None,
+            repeat: false,
+        }
+    }
 }
 
 /// Changed button state.
@@ -68,7 +108,7 @@ impl ButtonEvent for Event {
 pub trait PressEvent: Sized {
     /// Creates a press event.
     ///
-    /// Preserves scancode from original button event, if any.
+    /// Preserves scancode and key code from original button event, if any.
     /// Preserves time stamp from original input event, if any.
     fn from_button(button: Button, old_event: &Self) -> Option<Self>;
     /// Calls closure if this is a press event.
@@ -79,6 +119,8 @@ pub trait PressEvent: Sized {
     fn press_args(&self) -> Option<Button> {
         self.press(|button| button)
     }
+    /// Returns `true` if this is a repeated press of a held button.
+    fn is_repeat(&self) -> bool;
 }
 
 impl<T> PressEvent for T
@@ -91,14 +133,7 @@ where
             args.button = button;
             ButtonEvent::from_button_args(args, old_event)
         } else {
-            ButtonEvent::from_button_args(
-                ButtonArgs {
-                    state: ButtonState::Press,
-                    button,
-                    scancode: None,
-                },
-                old_event,
-            )
+            ButtonEvent::from_button_args(ButtonArgs::new(ButtonState::Press, button), old_event)
         }
     }
 
@@ -117,13 +152,24 @@ where
             None
         }
     }
+
+    fn is_repeat(&self) -> bool {
+        matches!(
+            self.button_args(),
+            Some(ButtonArgs {
+                state: ButtonState::Press,
+                repeat: true,
+                ..
+            })
+        )
+    }
 }
 
 /// The release of a button.
 pub trait ReleaseEvent: Sized {
     /// Creates a release event.
     ///
-    /// Preserves scancode from original button event, if any.
+    /// Preserves scancode and key code from original button event, if any.
     /// Preserves time stamp from original input event, if any.
     fn from_button(button: Button, old_event: &Self) -> Option<Self>;
     /// Calls closure if this is a release event.
@@ -144,16 +190,10 @@ where
         if let Some(mut args) = old_event.button_args() {
             args.state = ButtonState::Release;
             args.button = button;
+            args.repeat = false;
             ButtonEvent::from_button_args(args, old_event)
         } else {
-            ButtonEvent::from_button_args(
-                ButtonArgs {
-                    state: ButtonState::Release,
-                    button,
-                    scancode: None,
-                },
-                old_event,
-            )
+            ButtonEvent::from_button_args(ButtonArgs::new(ButtonState::Release, button), old_event)
         }
     }
 
@@ -182,12 +222,7 @@ mod tests {
     fn test_input_press() {
         use super::super::{Button, Key};
 
-        let e: Event = ButtonArgs {
-            state: ButtonState::Press,
-            button: Key::S.into(),
-            scancode: None,
-        }
-        .into();
+        let e: Event = ButtonArgs::new(ButtonState::Press, Key::S.into()).into();
         let button = Button::Keyboard(Key::A);
         let x: Option<Event> = PressEvent::from_button(button, &e);
         let y: Option<Event> = x
@@ -202,12 +237,7 @@ mod tests {
     fn test_input_release() {
         use super::super::{Button, Key};
 
-        let e: Event = ButtonArgs {
-            state: ButtonState::Release,
-            button: Key::S.into(),
-            scancode: None,
-        }
-        .into();
+        let e: Event = ButtonArgs::new(ButtonState::Release, Key::S.into()).into();
         let button = Button::Keyboard(Key::A);
         let x: Option<Event> = ReleaseEvent::from_button(button, &e);
         let y: Option<Event> = x
@@ -217,4 +247,50 @@ mod tests {
             .unwrap();
         assert_eq!(x, y);
     }
+
+    #[test]
+    fn test_input_unknown_keycode() {
+        use super::super::{Button, Key};
+
+        let code = 0x4000_0200;
+        let e: Event = ButtonArgs {
+            keycode: Some(code),
+            ..ButtonArgs::new(ButtonState::Press, Key::from(code).into())
+        }
+        .into();
+        assert_eq!(e.press_args(), Some(Button::Keyboard(Key::Unknown)));
+        assert_eq!(e.button_args().unwrap().keycode, Some(code));
+
+        let release: Event = ReleaseEvent::from_button(Key::Unknown.into(), &e).unwrap();
+        assert_eq!(release.release_args(), Some(Button::Keyboard(Key::Unknown)));
+        assert_eq!(release.button_args().unwrap().keycode, Some(code));
+    }
+
+    #[test]
+    fn test_input_press_repeat() {
+        use super::super::Key;
+
+        let press = ButtonArgs::new(ButtonState::Press, Key::Left.into());
+        let e: Event = press.into();
+        assert!(!e.is_repeat());
+        let e: Event = ButtonArgs {
+            repeat: true,
+            ..press
+        }
+        .into();
+        assert!(e.is_repeat());
+        let release: Event = ReleaseEvent::from_button(Key::Left.into(), &e).unwrap();
+        assert!(!release.button_args().unwrap().repeat);
+    }
+
+    #[test]
+    fn test_input_press_direct() {
+        use super::super::{Button, Key};
+
+        let e: Event = ButtonArgs::new(ButtonState::Release, Key::S.into()).into();
+        let button = Button::Keyboard(Key::A);
+        let x: Option<Event> = PressEvent::from_button(button, &e);
+        let y: Event = ButtonArgs::new(ButtonState::Press, button).into();
+        assert_eq!(x, Some(y));
+    }
 }
@@ -0,0 +1,101 @@
+use std::collections::BTreeSet;
+
+use crate::{Button, GenericEvent};
+
+/// Tells which buttons were pressed or released during the current frame,
+/// e.g. to trigger a jump only on the frame a key was pressed.
+///
+/// Only a press of a button that is not held counts as pressed,
+/// so repeated presses of a held key are ignored.
+/// Held buttons are forgotten when the window loses focus.
+///
+/// An update event ends a frame.
+/// The edges stay available while handling the update event,
+/// and are cleared when the next event is handled.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ButtonEdges {
+    pressed: BTreeSet<Button>,
+    released: BTreeSet<Button>,
+    held: BTreeSet<Button>,
+    frame_ended: bool,
+}
+
+impl ButtonEdges {
+    /// Creates a new button edge tracker.
+    pub fn new() -> ButtonEdges {
+        ButtonEdges::default()
+    }
+
+    /// Returns true if a button was pressed during the current frame.
+    pub fn just_pressed(&self, button: Button) -> bool {
+        self.pressed.contains(&button)
+    }
+
+    /// Returns true if a button was released during the current frame.
+    pub fn just_released(&self, button: Button) -> bool {
+        self.released.contains(&button)
+    }
+
+    /// Handles an event.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) {
+        if self.frame_ended {
+            self.frame_ended = false;
+            self.pressed.clear();
+            self.released.clear();
+        }
+        if let Some(button) = e.press_args() {
+            if self.held.insert(button) {
+                self.pressed.insert(button);
+            }
+        }
+        if let Some(button) = e.release_args() {
+            self.held.remove(&button);
+            self.released.insert(button);
+        }
+        if let Some(false) = e.focus_args() {
+            self.held.clear();
+        }
+        if e.update_args().is_some() {
+            self.frame_ended = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event, Key, UpdateArgs};
+
+    #[test]
+    fn test_just_pressed() {
+        let mut edges = ButtonEdges::new();
+        let space = Button::Keyboard(Key::Space);
+        let update: Event = UpdateArgs { dt: 0.1 }.into();
+        edges.event(&Event::from(ButtonArgs::new(ButtonState::Press, space)));
+        edges.event(&update);
+        assert!(edges.just_pressed(space));
+        assert!(!edges.just_released(space));
+        edges.event(&update);
+        assert!(!edges.just_pressed(space));
+    }
+
+    #[test]
+    fn test_just_pressed_repeat() {
+        let mut edges = ButtonEdges::new();
+        let space = Button::Keyboard(Key::Space);
+        let press = ButtonArgs::new(ButtonState::Press, space);
+        let update: Event = UpdateArgs { dt: 0.1 }.into();
+        edges.event(&Event::from(press));
+        edges.event(&update);
+        edges.event(&Event::from(ButtonArgs {
+            repeat: true,
+            ..press
+        }));
+        edges.event(&update);
+        assert!(!edges.just_pressed(space));
+        // A held button is pressed again without a repeat flag.
+        edges.event(&Event::from(press));
+        edges.event(&update);
+        assert!(!edges.just_pressed(space));
+    }
+}
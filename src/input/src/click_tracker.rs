@@ -0,0 +1,120 @@
+use crate::{Button, GenericEvent, MouseButton};
+
+/// The default maximum time in seconds between clicks of a multi-click.
+const DEFAULT_MAX_INTERVAL: f64 = 0.5;
+/// The default maximum cursor distance between clicks of a multi-click.
+const DEFAULT_MAX_DISTANCE: f64 = 4.0;
+/// The default maximum click count.
+const DEFAULT_MAX_COUNT: u32 = 3;
+
+/// Counts mouse clicks for double and triple clicks,
+/// e.g. to select a word or a line in a text editor.
+///
+/// A press of the same mouse button increments the click count
+/// when it is within the maximum time and cursor distance of the previous press.
+/// Otherwise, the click count starts over at 1.
+///
+/// Time is measured by update events.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClickTracker {
+    /// The maximum time in seconds between clicks of a multi-click.
+    pub max_interval: f64,
+    /// The maximum cursor distance between clicks of a multi-click.
+    pub max_distance: f64,
+    /// The maximum click count.
+    ///
+    /// When reached, the next click starts over at 1.
+    pub max_count: u32,
+    cursor: [f64; 2],
+    elapsed: f64,
+    last: Option<(MouseButton, [f64; 2])>,
+    count: u32,
+}
+
+impl ClickTracker {
+    /// Creates a new click tracker with default thresholds.
+    pub fn new() -> ClickTracker {
+        ClickTracker {
+            max_interval: DEFAULT_MAX_INTERVAL,
+            max_distance: DEFAULT_MAX_DISTANCE,
+            max_count: DEFAULT_MAX_COUNT,
+            cursor: [0.0; 2],
+            elapsed: 0.0,
+            last: None,
+            count: 0,
+        }
+    }
+
+    /// Returns the current click count.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the mouse button and click count when a mouse button is pressed.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<(MouseButton, u32)> {
+        if let Some(pos) = e.mouse_cursor_args() {
+            self.cursor = pos;
+        }
+        if let Some(args) = e.update_args() {
+            self.elapsed += args.dt;
+        }
+        if let Some(Button::Mouse(button)) = e.press_args() {
+            let repeated = match self.last {
+                Some((last_button, pos)) => {
+                    last_button == button
+                        && self.elapsed <= self.max_interval
+                        && (self.cursor[0] - pos[0]).hypot(self.cursor[1] - pos[1])
+                            <= self.max_distance
+                        && self.count < self.max_count
+                }
+                None => false,
+            };
+            self.count = if repeated { self.count + 1 } else { 1 };
+            self.last = Some((button, self.cursor));
+            self.elapsed = 0.0;
+            return Some((button, self.count));
+        }
+        None
+    }
+}
+
+impl Default for ClickTracker {
+    fn default() -> ClickTracker {
+        ClickTracker::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event, Motion, UpdateArgs};
+
+    #[test]
+    fn test_triple_click() {
+        let press: Event = ButtonArgs::new(ButtonState::Press, MouseButton::Left.into()).into();
+        let mut clicks = ClickTracker::new();
+        let cursor: Event = Motion::MouseCursor([10.0, 20.0]).into();
+        let update: Event = UpdateArgs { dt: 0.1 }.into();
+        assert_eq!(clicks.event(&cursor), None);
+        assert_eq!(clicks.event(&press), Some((MouseButton::Left, 1)));
+        clicks.event(&update);
+        assert_eq!(clicks.event(&press), Some((MouseButton::Left, 2)));
+        clicks.event(&update);
+        assert_eq!(clicks.event(&press), Some((MouseButton::Left, 3)));
+        // The maximum count is reached.
+        assert_eq!(clicks.event(&press), Some((MouseButton::Left, 1)));
+    }
+
+    #[test]
+    fn test_click_reset() {
+        let press: Event = ButtonArgs::new(ButtonState::Press, MouseButton::Left.into()).into();
+        let mut clicks = ClickTracker::new();
+        assert_eq!(clicks.event(&press), Some((MouseButton::Left, 1)));
+        clicks.event(&Event::from(UpdateArgs { dt: 1.0 }));
+        assert_eq!(clicks.event(&press), Some((MouseButton::Left, 1)));
+        clicks.event(&Event::from(Motion::MouseCursor([100.0, 0.0])));
+        assert_eq!(clicks.event(&press), Some((MouseButton::Left, 1)));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{Button, GenericEvent, MouseButton};
+
+/// The axis a drag is constrained to.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DragAxis {
+    /// Drag in any direction.
+    #[default]
+    None,
+    /// Drag only horizontally.
+    Horizontal,
+    /// Drag only vertically.
+    Vertical,
+}
+
+/// An action reported by a [`DragController`](struct.DragController.html).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DragAction {
+    /// The cursor moved while dragging.
+    Drag {
+        /// The drag delta since the previous drag action.
+        delta: [f64; 2],
+        /// The cursor position.
+        ///
+        /// When the drag is constrained to an axis,
+        /// the perpendicular component stays at the press position.
+        pos: [f64; 2],
+    },
+    /// The mouse button was released before the drag threshold was crossed.
+    Click,
+}
+
+/// Tracks dragging with a mouse button, e.g. for sliders or moving objects.
+///
+/// A drag starts when the cursor moves further than the drag threshold
+/// while the mouse button is pressed, and ends when the button is released.
+/// While dragging, cursor movement is reported as a drag delta and position.
+/// The drag deltas add up to the position relative to the press position.
+/// A press and release without crossing the threshold is reported as a click,
+/// so the same controller can handle both clicks and drags.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DragController {
+    /// The mouse button used for dragging.
+    pub button: MouseButton,
+    /// The axis the drag is constrained to.
+    pub axis: DragAxis,
+    /// The distance the cursor must move after pressing before a drag starts.
+    pub threshold: f64,
+    cursor: [f64; 2],
+    press_pos: Option<[f64; 2]>,
+    dragging: bool,
+}
+
+impl DragController {
+    /// Creates a new drag controller using the left mouse button,
+    /// without a drag threshold.
+    pub fn new() -> DragController {
+        DragController {
+            button: MouseButton::Left,
+            axis: DragAxis::None,
+            threshold: 0.0,
+            cursor: [0.0; 2],
+            press_pos: None,
+            dragging: false,
+        }
+    }
+
+    /// Sets the axis the drag is constrained to.
+    ///
+    /// The perpendicular component of the drag delta is set to zero.
+    pub fn set_constrain(&mut self, axis: DragAxis) {
+        self.axis = axis;
+    }
+
+    /// Sets the axis the drag is constrained to.
+    ///
+    /// This method moves the current drag controller,
+    /// unlike [`set_constrain()`](#method.set_constrain),
+    /// so that it can be used in method chaining.
+    pub fn constrain(mut self, axis: DragAxis) -> Self {
+        self.set_constrain(axis);
+        self
+    }
+
+    /// Sets the distance the cursor must move after pressing before a drag starts.
+    pub fn set_threshold(&mut self, threshold: f64) {
+        self.threshold = threshold;
+    }
+
+    /// Sets the distance the cursor must move after pressing before a drag starts.
+    ///
+    /// This method moves the current drag controller,
+    /// unlike [`set_threshold()`](#method.set_threshold),
+    /// so that it can be used in method chaining.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.set_threshold(threshold);
+        self
+    }
+
+    /// Returns true if dragging.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the drag delta and position when the cursor moves while dragging,
+    /// or a click when the mouse button is released without dragging.
+    /// The first drag delta includes the movement below the threshold.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<DragAction> {
+        if e.press_args() == Some(Button::Mouse(self.button)) {
+            self.press_pos = Some(self.cursor);
+        }
+        if e.release_args() == Some(Button::Mouse(self.button)) {
+            let clicked = self.press_pos.is_some() && !self.dragging;
+            self.press_pos = None;
+            self.dragging = false;
+            if clicked {
+                return Some(DragAction::Click);
+            }
+        }
+        if let Some(false) = e.focus_args() {
+            self.press_pos = None;
+            self.dragging = false;
+        }
+        let pos = e.mouse_cursor_args()?;
+        let mut delta = [pos[0] - self.cursor[0], pos[1] - self.cursor[1]];
+        self.cursor = pos;
+        let press_pos = self.press_pos?;
+        if !self.dragging {
+            let moved = [pos[0] - press_pos[0], pos[1] - press_pos[1]];
+            if moved[0].hypot(moved[1]) <= self.threshold {
+                return None;
+            }
+            self.dragging = true;
+            delta = moved;
+        }
+        Some(match self.axis {
+            DragAxis::None => DragAction::Drag { delta, pos },
+            DragAxis::Horizontal => DragAction::Drag {
+                delta: [delta[0], 0.0],
+                pos: [pos[0], press_pos[1]],
+            },
+            DragAxis::Vertical => DragAction::Drag {
+                delta: [0.0, delta[1]],
+                pos: [press_pos[0], pos[1]],
+            },
+        })
+    }
+}
+
+impl Default for DragController {
+    fn default() -> DragController {
+        DragController::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event, Motion};
+
+    #[test]
+    fn test_drag_horizontal() {
+        let press: Event = ButtonArgs::new(ButtonState::Press, MouseButton::Left.into()).into();
+        let release: Event = ButtonArgs::new(ButtonState::Release, MouseButton::Left.into()).into();
+        let mut drag = DragController::new().constrain(DragAxis::Horizontal);
+        let cursor = |pos| Event::from(Motion::MouseCursor(pos));
+        assert_eq!(drag.event(&cursor([10.0, 10.0])), None);
+        assert_eq!(drag.event(&press), None);
+        assert_eq!(
+            drag.event(&cursor([15.0, 20.0])),
+            Some(DragAction::Drag {
+                delta: [5.0, 0.0],
+                pos: [15.0, 10.0]
+            })
+        );
+        assert_eq!(drag.event(&release), None);
+        assert_eq!(drag.event(&cursor([30.0, 30.0])), None);
+    }
+
+    #[test]
+    fn test_drag_threshold_click() {
+        let press: Event = ButtonArgs::new(ButtonState::Press, MouseButton::Left.into()).into();
+        let release: Event = ButtonArgs::new(ButtonState::Release, MouseButton::Left.into()).into();
+        let mut drag = DragController::new().threshold(4.0);
+        let cursor = |pos| Event::from(Motion::MouseCursor(pos));
+        assert_eq!(drag.event(&cursor([10.0, 10.0])), None);
+        assert_eq!(drag.event(&press), None);
+        assert_eq!(drag.event(&cursor([12.0, 11.0])), None);
+        assert!(!drag.is_dragging());
+        assert_eq!(drag.event(&release), Some(DragAction::Click));
+
+        // Crossing the threshold starts a drag with the full movement.
+        assert_eq!(drag.event(&press), None);
+        assert_eq!(
+            drag.event(&cursor([17.0, 11.0])),
+            Some(DragAction::Drag {
+                delta: [5.0, 0.0],
+                pos: [17.0, 11.0]
+            })
+        );
+        assert!(drag.is_dragging());
+        assert_eq!(drag.event(&release), None);
+    }
+
+    #[test]
+    fn test_drag_positions() {
+        let press: Event = ButtonArgs::new(ButtonState::Press, MouseButton::Left.into()).into();
+        let mut drag = DragController::new();
+        let cursor = |pos| Event::from(Motion::MouseCursor(pos));
+        drag.event(&cursor([10.0, 10.0]));
+        drag.event(&press);
+        let mut sum = [10.0, 10.0];
+        for pos in [[12.0, 10.0], [15.0, 14.0], [11.0, 20.0]] {
+            match drag.event(&cursor(pos)) {
+                Some(DragAction::Drag {
+                    delta,
+                    pos: reported,
+                }) => {
+                    sum = [sum[0] + delta[0], sum[1] + delta[1]];
+                    assert_eq!(reported, pos);
+                    assert_eq!(sum, pos);
+                }
+                x => panic!("expected drag, got {:?}", x),
+            }
+        }
+    }
+}
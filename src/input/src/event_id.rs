@@ -24,12 +24,16 @@ pub const BUTTON: EventId = EventId("piston/button");
 pub const RENDER: EventId = EventId("piston/render");
 /// Event id for resize event.
 pub const RESIZE: EventId = EventId("piston/resize");
+/// Event id for scale factor changed event.
+pub const SCALE_FACTOR_CHANGED: EventId = EventId("piston/scale_factor_changed");
 /// Event id for text event.
 pub const TEXT: EventId = EventId("piston/text");
 /// Event id for touch event.
 pub const TOUCH: EventId = EventId("piston/touch");
 /// Event id for update event.
 pub const UPDATE: EventId = EventId("piston/update");
+/// Event id for slow update event.
+pub const SLOW_UPDATE: EventId = EventId("piston/slow_update");
 /// Event id for file drag event.
 pub const FILE_DRAG: EventId = EventId("piston/file_drag");
 
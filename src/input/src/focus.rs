@@ -1,6 +1,38 @@
-use crate::{Event, Input};
+use crate::{Event, GenericEvent, Input};
 
-/// When window gets or loses focus.
+/// The keyboard and pointer focus of a window.
+///
+/// Keyboard focus is reported by [`FocusEvent`](trait.FocusEvent.html),
+/// and pointer focus, when the mouse cursor is over the window,
+/// is reported by [`CursorEvent`](trait.CursorEvent.html).
+/// The focus is kept up to date by handling events.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FocusArgs {
+    /// Whether the window has keyboard focus.
+    pub keyboard: bool,
+    /// Whether the mouse cursor is over the window.
+    pub pointer: bool,
+}
+
+impl FocusArgs {
+    /// Handles an event.
+    ///
+    /// Returns the new focus when receiving a focus or cursor event.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<FocusArgs> {
+        if let Some(focused) = e.focus_args() {
+            self.keyboard = focused;
+        } else if let Some(cursor) = e.cursor_args() {
+            self.pointer = cursor;
+        } else {
+            return None;
+        }
+        Some(*self)
+    }
+}
+
+/// When window gets or loses keyboard focus.
+///
+/// See [`FocusArgs`](struct.FocusArgs.html) for tracking keyboard and pointer focus.
 pub trait FocusEvent: Sized {
     /// Creates a focus event.
     ///
@@ -54,4 +86,24 @@ mod tests {
             .unwrap();
         assert_eq!(x, y);
     }
+
+    #[test]
+    fn test_focus_args() {
+        let mut focus = FocusArgs::default();
+        assert_eq!(
+            focus.event(&Event::from(Input::Cursor(true))),
+            Some(FocusArgs {
+                keyboard: false,
+                pointer: true
+            })
+        );
+        assert_eq!(
+            focus.event(&Event::from(Input::Focus(true))),
+            Some(FocusArgs {
+                keyboard: true,
+                pointer: true
+            })
+        );
+        assert_eq!(focus.event(&Event::from(Input::Text("a".into()))), None);
+    }
 }
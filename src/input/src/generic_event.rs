@@ -5,8 +5,8 @@ use std::any::Any;
 use crate::{
     AfterRenderEvent, ButtonEvent, CloseEvent, ControllerAxisEvent, CursorEvent, Event, EventId,
     FocusEvent, IdleEvent, Input, Loop, Motion, MouseCursorEvent, MouseRelativeEvent,
-    MouseScrollEvent, PressEvent, ReleaseEvent, RenderEvent, ResizeEvent, TextEvent, TimeStamp,
-    TouchEvent, UpdateEvent,
+    MouseScrollEvent, PressEvent, ReleaseEvent, RenderEvent, ResizeEvent, ScaleFactorChangedEvent,
+    SlowUpdateEvent, TextEvent, TimeStamp, TouchEvent, UpdateEvent,
 };
 
 /// Implemented by all events.
@@ -35,6 +35,8 @@ pub trait GenericEvent:
     + ReleaseEvent
     + RenderEvent
     + ResizeEvent
+    + ScaleFactorChangedEvent
+    + SlowUpdateEvent
     + TextEvent
     + TouchEvent
     + UpdateEvent
@@ -66,16 +68,19 @@ impl GenericEvent for Event {
             Event::Input(Input::Move(Motion::MouseCursor(_)), _) => MOUSE_CURSOR,
             Event::Input(Input::Move(Motion::MouseRelative(_)), _) => MOUSE_RELATIVE,
             Event::Input(Input::Move(Motion::MouseScroll(_)), _) => MOUSE_SCROLL,
+            Event::Input(Input::Move(Motion::MouseScrollPixels(_)), _) => MOUSE_SCROLL,
             Event::Input(Input::Move(Motion::ControllerAxis(_)), _) => CONTROLLER_AXIS,
             Event::Input(Input::Move(Motion::Touch(_)), _) => TOUCH,
             Event::Input(Input::Button(_), _) => BUTTON,
             Event::Input(Input::Resize(_), _) => RESIZE,
+            Event::Input(Input::ScaleFactorChanged(_), _) => SCALE_FACTOR_CHANGED,
             Event::Input(Input::Text(_), _) => TEXT,
             Event::Input(Input::FileDrag(_), _) => FILE_DRAG,
             Event::Loop(Loop::Update(_)) => UPDATE,
             Event::Loop(Loop::Render(_)) => RENDER,
             Event::Loop(Loop::AfterRender(_)) => AFTER_RENDER,
             Event::Loop(Loop::Idle(_)) => IDLE,
+            Event::Loop(Loop::SlowUpdate(_)) => SLOW_UPDATE,
             Event::Custom(event_id, _, _) => event_id,
         }
     }
@@ -92,16 +97,19 @@ impl GenericEvent for Event {
             Event::Input(Input::Move(Motion::MouseCursor(pos)), _) => f(&pos as &dyn Any),
             Event::Input(Input::Move(Motion::MouseRelative(pos)), _) => f(&pos as &dyn Any),
             Event::Input(Input::Move(Motion::MouseScroll(pos)), _) => f(&pos as &dyn Any),
+            Event::Input(Input::Move(Motion::MouseScrollPixels(pos)), _) => f(&pos as &dyn Any),
             Event::Input(Input::Move(Motion::Touch(args)), _) => f(&args as &dyn Any),
             Event::Input(Input::Button(ref args), _) => f(args as &dyn Any),
             Event::Input(Input::Resize(ref args), _) => f(args as &dyn Any),
+            Event::Input(Input::ScaleFactorChanged(ref args), _) => f(args as &dyn Any),
             Event::Input(Input::Text(ref text), _) => f(text as &dyn Any),
             Event::Input(Input::FileDrag(ref file_drag), _) => f(file_drag as &dyn Any),
             Event::Loop(Loop::Update(ref args)) => f(args as &dyn Any),
             Event::Loop(Loop::Render(ref args)) => f(args as &dyn Any),
             Event::Loop(Loop::AfterRender(ref args)) => f(args as &dyn Any),
             Event::Loop(Loop::Idle(ref args)) => f(args as &dyn Any),
-            Event::Custom(_, ref args, _) => f(args),
+            Event::Loop(Loop::SlowUpdate(ref args)) => f(args as &dyn Any),
+            Event::Custom(_, ref args, _) => f(&**args),
         }
     }
 
@@ -113,3 +121,66 @@ impl GenericEvent for Event {
         }
     }
 }
+
+/// An object safe interface to events, e.g. for storing `Box<dyn DynEvent>`
+/// in a queue of different event types.
+///
+/// This is implemented for all types that implement `GenericEvent`.
+/// The methods have different names than in `GenericEvent`,
+/// so that both traits can be used at the same time.
+pub trait DynEvent {
+    /// The id of this event.
+    fn dyn_event_id(&self) -> EventId;
+    /// Calls closure with arguments.
+    fn dyn_with_args(&self, f: &mut dyn FnMut(&dyn Any));
+    /// Gets the time stamp of this event.
+    ///
+    /// Measured in milliseconds since initialization of window.
+    fn dyn_time_stamp(&self) -> Option<TimeStamp>;
+}
+
+impl<T: GenericEvent> DynEvent for T {
+    fn dyn_event_id(&self) -> EventId {
+        self.event_id()
+    }
+
+    fn dyn_with_args(&self, f: &mut dyn FnMut(&dyn Any)) {
+        self.with_args(|args| f(args))
+    }
+
+    fn dyn_time_stamp(&self) -> Option<TimeStamp> {
+        self.time_stamp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{event_id, UpdateArgs};
+
+    #[test]
+    fn test_dyn_event() {
+        let events: Vec<Box<dyn DynEvent>> = vec![
+            Box::new(Event::from(Input::Text("a".into()))),
+            Box::new(Event::from(UpdateArgs { dt: 0.5 })),
+            Box::new(Event::from(Input::Focus(true))),
+        ];
+        let mut handled = vec![];
+        for e in &events {
+            match e.dyn_event_id() {
+                event_id::TEXT => e.dyn_with_args(&mut |args| {
+                    handled.push(format!("text {}", args.downcast_ref::<String>().unwrap()))
+                }),
+                event_id::UPDATE => e.dyn_with_args(&mut |args| {
+                    let args = args.downcast_ref::<UpdateArgs>().unwrap();
+                    handled.push(format!("update {}", args.dt))
+                }),
+                id => handled.push(format!("{:?}", id)),
+            }
+        }
+        assert_eq!(
+            handled,
+            vec!["text a", "update 0.5", "EventId(\"piston/focus\")"]
+        );
+    }
+}
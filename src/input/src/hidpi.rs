@@ -0,0 +1,86 @@
+use crate::{Event, Input};
+
+/// Scale factor changed arguments.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct ScaleFactorChangedArgs {
+    /// The new ratio between pixels and points.
+    pub scale_factor: f64,
+    /// The new width and height of rendered area in pixels.
+    pub new_draw_size: [u32; 2],
+}
+
+/// When the scale factor of the window changes,
+/// e.g. when moving the window between monitors with different DPI.
+///
+/// Fonts and layouts that depend on the scale factor should be updated
+/// when receiving this event.
+///
+/// The size of the window in points usually stays the same,
+/// while the draw size in pixels changes with the scale factor.
+/// A window back-end might also emit a [`ResizeEvent`](trait.ResizeEvent.html)
+/// with the new draw size, so applications that only care about pixel sizes
+/// can keep handling resize events.
+pub trait ScaleFactorChangedEvent: Sized {
+    /// Creates a scale factor changed event.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    fn from_scale_factor_changed_args(
+        args: &ScaleFactorChangedArgs,
+        old_event: &Self,
+    ) -> Option<Self>;
+    /// Calls closure if this is a scale factor changed event.
+    fn scale_factor_changed<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnMut(&ScaleFactorChangedArgs) -> U;
+    /// Returns scale factor changed arguments.
+    fn scale_factor_changed_args(&self) -> Option<ScaleFactorChangedArgs> {
+        self.scale_factor_changed(|args| *args)
+    }
+}
+
+impl ScaleFactorChangedEvent for Event {
+    fn from_scale_factor_changed_args(
+        args: &ScaleFactorChangedArgs,
+        old_event: &Self,
+    ) -> Option<Self> {
+        let timestamp = if let Event::Input(_, x) = old_event {
+            *x
+        } else {
+            None
+        };
+        Some(Event::Input(Input::ScaleFactorChanged(*args), timestamp))
+    }
+
+    fn scale_factor_changed<U, F>(&self, mut f: F) -> Option<U>
+    where
+        F: FnMut(&ScaleFactorChangedArgs) -> U,
+    {
+        match *self {
+            Event::Input(Input::ScaleFactorChanged(ref args), _) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_scale_factor_changed() {
+        let args = ScaleFactorChangedArgs {
+            scale_factor: 2.0,
+            new_draw_size: [200, 200],
+        };
+        let e: Event = Input::ScaleFactorChanged(args).into();
+        let x: Option<Event> = ScaleFactorChangedEvent::from_scale_factor_changed_args(&args, &e);
+        let y: Option<Event> = x
+            .clone()
+            .unwrap()
+            .scale_factor_changed(|args| {
+                ScaleFactorChangedEvent::from_scale_factor_changed_args(args, x.as_ref().unwrap())
+            })
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use crate::{Button, GenericEvent};
+
+/// Measures how long buttons are held down,
+/// e.g. for charge attacks that depend on the hold duration.
+///
+/// Time is measured by update events.
+/// Held buttons are released when the window loses focus.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HoldTracker {
+    held: BTreeMap<Button, f64>,
+}
+
+impl HoldTracker {
+    /// Creates a new hold tracker without any held buttons.
+    pub fn new() -> HoldTracker {
+        HoldTracker::default()
+    }
+
+    /// Returns the time in seconds a button has been held down,
+    /// or `None` if the button is not held down.
+    pub fn hold_time(&self, button: Button) -> Option<f64> {
+        self.held.get(&button).copied()
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the button and the time in seconds it was held down
+    /// when the button is released.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<(Button, f64)> {
+        if let Some(args) = e.update_args() {
+            for time in self.held.values_mut() {
+                *time += args.dt;
+            }
+        }
+        if let Some(button) = e.press_args() {
+            self.held.entry(button).or_insert(0.0);
+        }
+        if let Some(false) = e.focus_args() {
+            self.held.clear();
+        }
+        let button = e.release_args()?;
+        self.held.remove(&button).map(|time| (button, time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event, Key, UpdateArgs};
+
+    #[test]
+    fn test_hold_time() {
+        let press: Event = ButtonArgs::new(ButtonState::Press, Key::Space.into()).into();
+        let release: Event = ButtonArgs::new(ButtonState::Release, Key::Space.into()).into();
+        let mut tracker = HoldTracker::new();
+        let space = Button::Keyboard(Key::Space);
+        assert_eq!(tracker.hold_time(space), None);
+        assert_eq!(tracker.event(&press), None);
+        let update: Event = UpdateArgs { dt: 0.1 }.into();
+        for _ in 0..5 {
+            tracker.event(&update);
+        }
+        assert!((tracker.hold_time(space).unwrap() - 0.5).abs() < 1e-9);
+        let (released, time) = tracker.event(&release).unwrap();
+        assert_eq!(released, space);
+        assert!((time - 0.5).abs() < 1e-9);
+        assert_eq!(tracker.hold_time(space), None);
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::{
+    keyboard::ModifierKey, Button, ButtonArgs, ButtonState, Event, GenericEvent, Input, Motion,
+    MouseButton,
+};
+
+/// The input state at a frame, e.g. for rollback netcode.
+///
+/// The snapshot is kept up to date by handling events,
+/// and can be serialized to send or store a frame.
+/// Use [`diff()`](#method.diff) and [`apply()`](#method.apply)
+/// to compare frames and reconstruct one frame from another.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct InputSnapshot {
+    /// The buttons that are held down, kept sorted.
+    #[serde(deserialize_with = "deserialize_sorted")]
+    held: Vec<Button>,
+    /// The mouse cursor position.
+    pub cursor: [f64; 2],
+    /// The modifier keys that are held down.
+    pub modifiers: ModifierKey,
+}
+
+impl InputSnapshot {
+    /// Creates a new snapshot without any held buttons.
+    pub fn new() -> InputSnapshot {
+        InputSnapshot::default()
+    }
+
+    /// Returns true if a button is held down.
+    pub fn is_held(&self, button: Button) -> bool {
+        self.held.binary_search(&button).is_ok()
+    }
+
+    /// Returns the number of buttons held down.
+    ///
+    /// See [`KeyRollover`](struct.KeyRollover.html)
+    /// for warning about too many keys held down.
+    pub fn pressed_count(&self) -> usize {
+        self.held.len()
+    }
+
+    /// Returns the buttons held down in sorted order, without allocating,
+    /// e.g. to highlight keys on an on-screen keyboard.
+    pub fn pressed(&self) -> &[Button] {
+        &self.held
+    }
+
+    /// Returns the mouse buttons held down.
+    pub fn mouse_buttons(&self) -> impl Iterator<Item = MouseButton> + '_ {
+        self.held.iter().filter_map(|&button| match button {
+            Button::Mouse(button) => Some(button),
+            _ => None,
+        })
+    }
+
+    /// Handles an event.
+    ///
+    /// Held buttons are released when the window loses focus.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) {
+        if let Some(button) = e.press_args() {
+            if let Err(i) = self.held.binary_search(&button) {
+                self.held.insert(i, button);
+            }
+        }
+        if let Some(button) = e.release_args() {
+            if let Ok(i) = self.held.binary_search(&button) {
+                self.held.remove(i);
+            }
+        }
+        if let Some(pos) = e.mouse_cursor_args() {
+            self.cursor = pos;
+        }
+        if let Some(false) = e.focus_args() {
+            self.held.clear();
+        }
+        self.modifiers.event(e);
+    }
+
+    /// Returns the input state at render time between this snapshot and the next,
+    /// e.g. for interpolated rendering with snapshots taken at update events.
+    ///
+    /// The interpolation alpha is usually `ext_dt / dt`,
+    /// where `ext_dt` is from the render arguments and `dt` is the update time step.
+    /// The cursor position is interpolated,
+    /// while held buttons and modifier keys are taken from the next snapshot.
+    pub fn interpolate(&self, next: &InputSnapshot, alpha: f64) -> InputSnapshot {
+        let lerp = |a: f64, b: f64| a + (b - a) * alpha;
+        InputSnapshot {
+            held: next.held.clone(),
+            cursor: [
+                lerp(self.cursor[0], next.cursor[0]),
+                lerp(self.cursor[1], next.cursor[1]),
+            ],
+            modifiers: next.modifiers,
+        }
+    }
+
+    /// Returns the input needed to change this snapshot into another snapshot.
+    pub fn diff(&self, other: &InputSnapshot) -> Vec<Input> {
+        let button = |state, button| Input::Button(ButtonArgs::new(state, button));
+        let mut inputs: Vec<Input> = self
+            .held
+            .iter()
+            .filter(|&&b| !other.is_held(b))
+            .map(|&b| button(ButtonState::Release, b))
+            .collect();
+        inputs.extend(
+            other
+                .held
+                .iter()
+                .filter(|&&b| !self.is_held(b))
+                .map(|&b| button(ButtonState::Press, b)),
+        );
+        if self.cursor != other.cursor {
+            inputs.push(Input::Move(Motion::MouseCursor(other.cursor)));
+        }
+        inputs
+    }
+
+    /// Applies input, e.g. from [`diff()`](#method.diff).
+    pub fn apply(&mut self, inputs: &[Input]) {
+        for input in inputs {
+            self.event(&Event::from(input.clone()));
+        }
+    }
+}
+
+/// Deserializes buttons in any order, e.g. from a snapshot edited by hand.
+fn deserialize_sorted<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Button>, D::Error> {
+    let mut buttons: Vec<Button> = Vec::deserialize(deserializer)?;
+    buttons.sort();
+    buttons.dedup();
+    Ok(buttons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Key;
+
+    #[test]
+    fn test_snapshot_diff_apply() {
+        let mut a = InputSnapshot::new();
+        let mut b = InputSnapshot::new();
+        b.apply(&[
+            Input::Button(ButtonArgs::new(ButtonState::Press, Key::LCtrl.into())),
+            Input::Move(Motion::MouseCursor([3.0, 4.0])),
+        ]);
+        assert!(b.is_held(Key::LCtrl.into()));
+        assert_eq!(b.pressed_count(), 1);
+        assert_eq!(b.modifiers, ModifierKey::CTRL);
+
+        let diff = a.diff(&b);
+        assert_eq!(diff.len(), 2);
+        a.apply(&diff);
+        assert_eq!(a, b);
+        assert!(a.diff(&b).is_empty());
+
+        a.event(&Event::from(Input::Focus(false)));
+        assert!(a.held.is_empty());
+        assert_eq!(a.modifiers, ModifierKey::NO_MODIFIER);
+    }
+
+    #[test]
+    fn test_snapshot_interpolate() {
+        let mut a = InputSnapshot::new();
+        a.event(&Event::from(Motion::MouseCursor([10.0, 20.0])));
+        let mut b = a.clone();
+        b.event(&Event::from(Motion::MouseCursor([20.0, 40.0])));
+        assert_eq!(a.interpolate(&b, 0.5).cursor, [15.0, 30.0]);
+        assert_eq!(a.interpolate(&b, 1.0), b);
+    }
+
+    #[test]
+    fn test_snapshot_pressed() {
+        let mut snapshot = InputSnapshot::new();
+        for key in [Key::S, Key::A, Key::D] {
+            snapshot.event(&Event::from(ButtonArgs::new(
+                ButtonState::Press,
+                key.into(),
+            )));
+        }
+        snapshot.event(&Event::from(ButtonArgs::new(
+            ButtonState::Press,
+            Key::A.into(),
+        )));
+        snapshot.event(&Event::from(ButtonArgs::new(
+            ButtonState::Release,
+            Key::S.into(),
+        )));
+        assert_eq!(
+            snapshot.pressed(),
+            &[Button::Keyboard(Key::A), Button::Keyboard(Key::D)]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_mouse_buttons() {
+        let mut snapshot = InputSnapshot::new();
+        for button in [
+            Button::Mouse(MouseButton::Left),
+            Button::Keyboard(Key::A),
+            Button::Mouse(MouseButton::Right),
+        ] {
+            snapshot.event(&Event::from(ButtonArgs::new(ButtonState::Press, button)));
+        }
+        let buttons: Vec<_> = snapshot.mouse_buttons().collect();
+        assert_eq!(buttons, vec![MouseButton::Left, MouseButton::Right]);
+    }
+}
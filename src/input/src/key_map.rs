@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Button;
+
+/// Maps buttons to application specific actions, e.g. for key bindings.
+///
+/// Serializes as a list of `(button, action)` pairs sorted by button,
+/// such that it can be saved to formats that only support string keys in maps,
+/// and saved key bindings do not change order between runs.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KeyMap<A> {
+    bindings: BTreeMap<Button, A>,
+}
+
+impl<A> KeyMap<A> {
+    /// Creates a new empty key map.
+    pub fn new() -> KeyMap<A> {
+        KeyMap {
+            bindings: BTreeMap::new(),
+        }
+    }
+
+    /// Binds a button to an action.
+    ///
+    /// Returns the action that was previously bound to the button, if any.
+    pub fn bind(&mut self, button: Button, action: A) -> Option<A> {
+        self.bindings.insert(button, action)
+    }
+
+    /// Removes the binding of a button.
+    ///
+    /// Returns the action that was bound to the button, if any.
+    pub fn unbind(&mut self, button: Button) -> Option<A> {
+        self.bindings.remove(&button)
+    }
+
+    /// Returns the action bound to a button.
+    pub fn action_for(&self, button: Button) -> Option<&A> {
+        self.bindings.get(&button)
+    }
+
+    /// Returns the number of bindings.
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// Returns true if there are no bindings.
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+}
+
+impl<A> Default for KeyMap<A> {
+    fn default() -> KeyMap<A> {
+        KeyMap::new()
+    }
+}
+
+impl<A: Serialize> Serialize for KeyMap<A> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.bindings.iter())
+    }
+}
+
+impl<'de, A: Deserialize<'de>> Deserialize<'de> for KeyMap<A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pairs: Vec<(Button, A)> = Vec::deserialize(deserializer)?;
+        Ok(KeyMap {
+            bindings: pairs.into_iter().collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Key, MouseButton};
+
+    #[test]
+    fn test_key_map() {
+        let mut map = KeyMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.bind(Key::Space.into(), "jump"), None);
+        assert_eq!(map.bind(MouseButton::Left.into(), "shoot"), None);
+        assert_eq!(map.bind(Key::Space.into(), "fly"), Some("jump"));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.action_for(Key::Space.into()), Some(&"fly"));
+        assert_eq!(map.unbind(MouseButton::Left.into()), Some("shoot"));
+        assert_eq!(map.action_for(MouseButton::Left.into()), None);
+    }
+}
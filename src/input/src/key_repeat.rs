@@ -0,0 +1,126 @@
+use crate::{Button, GenericEvent, Key};
+
+/// Synthesizes repeated presses of a held keyboard key,
+/// e.g. for moving a text cursor or a menu selection.
+///
+/// Time is measured by update events.
+/// At most one repeat is emitted per update event.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KeyRepeat {
+    /// The delay in seconds before the first repeat.
+    pub delay: f64,
+    /// The number of repeats per second after the delay.
+    pub rate: f64,
+    held: Option<Key>,
+    next: f64,
+}
+
+impl KeyRepeat {
+    /// The default delay in seconds before the first repeat.
+    pub const DEFAULT_DELAY: f64 = 0.5;
+    /// The default number of repeats per second.
+    pub const DEFAULT_RATE: f64 = 30.0;
+
+    /// Creates a new key repeat helper with default delay and rate.
+    pub fn new() -> KeyRepeat {
+        KeyRepeat::from_settings(None)
+    }
+
+    /// Creates a new key repeat helper from key repeat settings `(delay, rate)`,
+    /// e.g. from `Window::key_repeat_settings` in the window crate.
+    ///
+    /// Falls back to the default delay and rate when the settings are not available.
+    pub fn from_settings(settings: Option<(f64, f64)>) -> KeyRepeat {
+        let (delay, rate) = settings.unwrap_or((Self::DEFAULT_DELAY, Self::DEFAULT_RATE));
+        KeyRepeat {
+            delay,
+            rate,
+            held: None,
+            next: 0.0,
+        }
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the held key when it repeats.
+    /// Only the last pressed key repeats,
+    /// and it stops when released or when the window loses focus.
+    /// Repeated presses from the operating system are ignored.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<Key> {
+        if let Some(Button::Keyboard(key)) = e.press_args() {
+            if !e.is_repeat() {
+                self.held = Some(key);
+                self.next = self.delay;
+            }
+        }
+        if let Some(Button::Keyboard(key)) = e.release_args() {
+            if self.held == Some(key) {
+                self.held = None;
+            }
+        }
+        if let Some(false) = e.focus_args() {
+            self.held = None;
+        }
+        let dt = e.update_args()?.dt;
+        let key = self.held?;
+        self.next -= dt;
+        if self.next <= 0.0 {
+            self.next = (self.next + 1.0 / self.rate).max(0.0);
+            Some(key)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for KeyRepeat {
+    fn default() -> KeyRepeat {
+        KeyRepeat::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event, UpdateArgs};
+
+    #[test]
+    fn test_key_repeat() {
+        let mut repeat = KeyRepeat::from_settings(Some((0.3, 10.0)));
+        let press: Event = ButtonArgs::new(ButtonState::Press, Key::Left.into()).into();
+        let update: Event = UpdateArgs { dt: 0.1 }.into();
+        assert_eq!(repeat.event(&press), None);
+        let repeats: Vec<_> = (0..6).map(|_| repeat.event(&update)).collect();
+        assert_eq!(
+            repeats,
+            vec![
+                None,
+                None,
+                Some(Key::Left),
+                Some(Key::Left),
+                Some(Key::Left),
+                Some(Key::Left)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_key_repeat_ignores_os_repeat() {
+        let mut repeat = KeyRepeat::from_settings(Some((0.3, 10.0)));
+        let press = ButtonArgs::new(ButtonState::Press, Key::Left.into());
+        let os_repeat: Event = ButtonArgs {
+            repeat: true,
+            ..press
+        }
+        .into();
+        let update: Event = UpdateArgs { dt: 0.1 }.into();
+        assert_eq!(repeat.event(&Event::from(press)), None);
+        let repeats: Vec<_> = (0..3)
+            .map(|_| {
+                repeat.event(&os_repeat);
+                repeat.event(&update)
+            })
+            .collect();
+        assert_eq!(repeats, vec![None, None, Some(Key::Left)]);
+    }
+}
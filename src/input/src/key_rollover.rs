@@ -0,0 +1,77 @@
+use std::collections::BTreeSet;
+
+use crate::{Button, GenericEvent, Key};
+
+/// Warns when more keys are held down than a keyboard might support,
+/// e.g. to tell players about key ghosting.
+///
+/// Many keyboards can not report some combinations of simultaneous keys.
+/// The hardware limit can not be detected, so the threshold is configurable.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyRollover {
+    /// The maximum number of keys held down at the same time without a warning.
+    pub max_simultaneous: usize,
+    held: BTreeSet<Key>,
+    warned: bool,
+}
+
+impl KeyRollover {
+    /// Creates a new rollover detector with a maximum number of simultaneous keys.
+    pub fn new(max_simultaneous: usize) -> KeyRollover {
+        KeyRollover {
+            max_simultaneous,
+            held: BTreeSet::new(),
+            warned: false,
+        }
+    }
+
+    /// Returns the number of keys held down.
+    pub fn pressed_count(&self) -> usize {
+        self.held.len()
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the number of keys held down when it first exceeds the maximum.
+    /// The warning is repeated after the number of keys drops to the maximum or below.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<usize> {
+        if let Some(Button::Keyboard(key)) = e.press_args() {
+            self.held.insert(key);
+        }
+        if let Some(Button::Keyboard(key)) = e.release_args() {
+            self.held.remove(&key);
+        }
+        if let Some(false) = e.focus_args() {
+            self.held.clear();
+        }
+        if self.held.len() <= self.max_simultaneous {
+            self.warned = false;
+        } else if !self.warned {
+            self.warned = true;
+            return Some(self.held.len());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event};
+
+    #[test]
+    fn test_key_rollover() {
+        let mut rollover = KeyRollover::new(3);
+        let warnings: Vec<_> = [Key::W, Key::A, Key::S, Key::D]
+            .iter()
+            .filter_map(|&key| {
+                rollover.event(&Event::from(ButtonArgs::new(
+                    ButtonState::Press,
+                    key.into(),
+                )))
+            })
+            .collect();
+        assert_eq!(warnings, vec![4]);
+        assert_eq!(rollover.pressed_count(), 4);
+    }
+}
@@ -84,6 +84,22 @@ impl ModifierKey {
             *self = ModifierKey::NO_MODIFIER;
         }
     }
+
+    /// Returns the modifier keys as a byte, e.g. for serialization.
+    ///
+    /// Bit 0 is Ctrl, bit 1 is Shift, bit 2 is Alt and bit 3 is Gui.
+    /// The other bits are zero.
+    /// This layout is stable and does not depend on the version of bitflags.
+    pub fn to_bits(&self) -> u8 {
+        self.bits()
+    }
+
+    /// Creates modifier keys from a byte, see [`to_bits()`](#method.to_bits).
+    ///
+    /// Bits that do not correspond to a modifier key are ignored.
+    pub fn from_u8(bits: u8) -> ModifierKey {
+        ModifierKey::from_bits_truncate(bits)
+    }
 }
 
 impl Default for ModifierKey {
@@ -586,8 +602,161 @@ impl Key {
     pub fn code(&self) -> i32 {
         *self as i32
     }
+
+    /// Returns the canonical key, folding aliases into a single key.
+    ///
+    /// This is useful to treat keys with the same meaning uniformly,
+    /// e.g. the numpad enter key the same as the return key.
+    /// Keys that are not aliases are returned unchanged.
+    ///
+    /// | Key | Canonical key |
+    /// |-----|---------------|
+    /// | `NumPadEnter`, `Return2` | `Return` |
+    /// | `NumPad0` ... `NumPad9` | `D0` ... `D9` |
+    /// | `NumPadPeriod` | `Period` |
+    /// | `NumPadComma` | `Comma` |
+    /// | `NumPadPlus` | `Plus` |
+    /// | `NumPadMinus` | `Minus` |
+    /// | `NumPadMultiply` | `Asterisk` |
+    /// | `NumPadDivide` | `Slash` |
+    /// | `NumPadEquals`, `NumPadEqualsAS400` | `Equals` |
+    /// | `NumPadTab` | `Tab` |
+    /// | `NumPadBackspace` | `Backspace` |
+    /// | `NumPadSpace` | `Space` |
+    ///
+    /// The numpad digits and period are folded regardless of the num lock state.
+    pub fn canonical(&self) -> Key {
+        match *self {
+            Key::NumPadEnter | Key::Return2 => Key::Return,
+            Key::NumPad0 => Key::D0,
+            Key::NumPad1 => Key::D1,
+            Key::NumPad2 => Key::D2,
+            Key::NumPad3 => Key::D3,
+            Key::NumPad4 => Key::D4,
+            Key::NumPad5 => Key::D5,
+            Key::NumPad6 => Key::D6,
+            Key::NumPad7 => Key::D7,
+            Key::NumPad8 => Key::D8,
+            Key::NumPad9 => Key::D9,
+            Key::NumPadPeriod => Key::Period,
+            Key::NumPadComma => Key::Comma,
+            Key::NumPadPlus => Key::Plus,
+            Key::NumPadMinus => Key::Minus,
+            Key::NumPadMultiply => Key::Asterisk,
+            Key::NumPadDivide => Key::Slash,
+            Key::NumPadEquals | Key::NumPadEqualsAS400 => Key::Equals,
+            Key::NumPadTab => Key::Tab,
+            Key::NumPadBackspace => Key::Backspace,
+            Key::NumPadSpace => Key::Space,
+            key => key,
+        }
+    }
+
+    /// Returns the conventional symbol of the key for display,
+    /// e.g. `'←'` for `Key::Left` or `'⇧'` for `Key::LShift`.
+    ///
+    /// Returns `None` for keys without a conventional symbol,
+    /// e.g. letters, which are usually displayed by their name.
+    /// Aliases are folded with [`canonical()`](#method.canonical) first,
+    /// e.g. `Key::NumPadEnter` has the same symbol as `Key::Return`.
+    pub fn symbol(&self) -> Option<char> {
+        Some(match self.canonical() {
+            Key::Left => '←',
+            Key::Right => '→',
+            Key::Up => '↑',
+            Key::Down => '↓',
+            Key::Return => '⏎',
+            Key::Backspace => '⌫',
+            Key::Delete => '⌦',
+            Key::Tab => '⇥',
+            Key::Escape => '⎋',
+            Key::Space => '␣',
+            Key::LShift | Key::RShift => '⇧',
+            Key::LCtrl | Key::RCtrl => '⌃',
+            Key::LAlt | Key::RAlt => '⌥',
+            Key::LGui | Key::RGui => '⌘',
+            Key::CapsLock => '⇪',
+            Key::Home => '↖',
+            Key::End => '↘',
+            Key::PageUp => '⇞',
+            Key::PageDown => '⇟',
+            Key::Eject => '⏏',
+            Key::Power => '⏻',
+            _ => return None,
+        })
+    }
+
+    /// Returns the key at a physical position on a US keyboard layout.
+    ///
+    /// Scancodes follow SDL (<https://wiki.libsdl.org/SDL_Scancode>),
+    /// the same as the scancode in [`ButtonArgs`](../struct.ButtonArgs.html).
+    /// Returns `Key::Unknown` for scancodes that have no matching key.
+    pub fn from_scancode(scancode: i32) -> Key {
+        match scancode {
+            4..=29 => Key::from(Key::A as u32 + (scancode - 4) as u32),
+            30..=38 => Key::from(Key::D1 as u32 + (scancode - 30) as u32),
+            39 => Key::D0,
+            40 => Key::Return,
+            41 => Key::Escape,
+            42 => Key::Backspace,
+            43 => Key::Tab,
+            44 => Key::Space,
+            45 => Key::Minus,
+            46 => Key::Equals,
+            47 => Key::LeftBracket,
+            48 => Key::RightBracket,
+            49 => Key::Backslash,
+            51 => Key::Semicolon,
+            52 => Key::Quote,
+            53 => Key::Backquote,
+            54 => Key::Comma,
+            55 => Key::Period,
+            56 => Key::Slash,
+            76 => Key::Delete,
+            x if x > 0 => Key::from(x as u32 | SCANCODE_MASK),
+            _ => Key::Unknown,
+        }
+    }
+
+    /// Returns the SDL scancode of the key on a US keyboard layout.
+    ///
+    /// Not all keys have a distinct scancode.
+    /// Keys for characters that are typed with modifiers, e.g. `Key::Exclaim`,
+    /// share the physical position with another key and return `None`.
+    pub fn scancode(&self) -> Option<i32> {
+        let code = u32::from(*self);
+        if code & SCANCODE_MASK != 0 {
+            return Some((code & !SCANCODE_MASK) as i32);
+        }
+        match *self {
+            x if Key::A <= x && x <= Key::Z => Some(4 + (code - Key::A as u32) as i32),
+            x if Key::D1 <= x && x <= Key::D9 => Some(30 + (code - Key::D1 as u32) as i32),
+            Key::D0 => Some(39),
+            Key::Return => Some(40),
+            Key::Escape => Some(41),
+            Key::Backspace => Some(42),
+            Key::Tab => Some(43),
+            Key::Space => Some(44),
+            Key::Minus => Some(45),
+            Key::Equals => Some(46),
+            Key::LeftBracket => Some(47),
+            Key::RightBracket => Some(48),
+            Key::Backslash => Some(49),
+            Key::Semicolon => Some(51),
+            Key::Quote => Some(52),
+            Key::Backquote => Some(53),
+            Key::Comma => Some(54),
+            Key::Period => Some(55),
+            Key::Slash => Some(56),
+            Key::Delete => Some(76),
+            _ => None,
+        }
+    }
 }
 
+/// SDL keycodes for keys without a character are scancodes with this bit set.
+const SCANCODE_MASK: u32 = 0x4000_0000;
+
 impl From<Key> for u32 {
     #[inline(always)]
     fn from(key: Key) -> u32 {
@@ -597,6 +766,27 @@ impl From<Key> for u32 {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn key_symbol() {
+        use super::Key;
+
+        assert_eq!(Key::Left.symbol(), Some('←'));
+        assert_eq!(Key::Return.symbol(), Some('⏎'));
+        assert_eq!(Key::NumPadEnter.symbol(), Some('⏎'));
+        assert_eq!(Key::LShift.symbol(), Some('⇧'));
+        assert_eq!(Key::A.symbol(), None);
+    }
+
+    #[test]
+    fn modifier_key_bits() {
+        use super::ModifierKey;
+
+        let bits = ModifierKey::CTRL_SHIFT.to_bits();
+        assert_eq!(bits, 0b0000_0011);
+        assert_eq!(ModifierKey::from_u8(bits), ModifierKey::CTRL_SHIFT);
+        assert_eq!(ModifierKey::from_u8(0b1111_0000), ModifierKey::NO_MODIFIER);
+    }
+
     #[test]
     fn keycode() {
         use super::{Key, Key::*};
@@ -845,4 +1035,35 @@ mod tests {
             assert_eq!(key, key2);
         }
     }
+
+    #[test]
+    fn scancode() {
+        use super::Key::{self, *};
+
+        let keys = [
+            A, B, M, Z, D0, D1, D9, Return, Space, Slash, Delete, Left, Right, Up, Down, F1, LCtrl,
+        ];
+        for &key in &keys {
+            let scancode = key.scancode().unwrap();
+            assert_eq!(Key::from_scancode(scancode), key);
+        }
+        assert_eq!(A.scancode(), Some(4));
+        assert_eq!(Right.scancode(), Some(79));
+        assert_eq!(Exclaim.scancode(), None);
+        assert_eq!(Unknown.scancode(), None);
+        assert_eq!(Key::from_scancode(0), Unknown);
+        assert_eq!(Key::from_scancode(-1), Unknown);
+    }
+
+    #[test]
+    fn canonical() {
+        use super::Key::*;
+
+        assert_eq!(NumPadEnter.canonical(), Return);
+        assert_eq!(Return2.canonical(), Return);
+        assert_eq!(Return.canonical(), Return);
+        assert_eq!(NumPad7.canonical(), D7);
+        assert_eq!(NumPadPeriod.canonical(), Period);
+        assert_eq!(A.canonical(), A);
+    }
 }
@@ -0,0 +1,307 @@
+//! Keyboard layouts for converting keys to characters.
+//!
+//! A layout interprets a [`Key`](../keyboard/enum.Key.html) as the physical position
+//! of the key on a US keyboard, e.g. from `Key::from_scancode`.
+//! For example, the key at the position of `Key::Z` types 'y' on a German keyboard.
+//!
+//! The Alt modifier selects the third level of a key (`AltGr`),
+//! while Ctrl and Gui are used for shortcuts and produce no characters.
+//! Dead keys, which combine with the next key, produce no characters.
+
+use crate::{keyboard::ModifierKey, Key};
+
+/// Marks a key without a character on a level.
+const NONE: char = '\0';
+
+/// The keys that type characters on the supported layouts.
+const CHAR_KEYS: &[Key] = &[
+    Key::Space,
+    Key::D0,
+    Key::D1,
+    Key::D2,
+    Key::D3,
+    Key::D4,
+    Key::D5,
+    Key::D6,
+    Key::D7,
+    Key::D8,
+    Key::D9,
+    Key::A,
+    Key::B,
+    Key::C,
+    Key::D,
+    Key::E,
+    Key::F,
+    Key::G,
+    Key::H,
+    Key::I,
+    Key::J,
+    Key::K,
+    Key::L,
+    Key::M,
+    Key::N,
+    Key::O,
+    Key::P,
+    Key::Q,
+    Key::R,
+    Key::S,
+    Key::T,
+    Key::U,
+    Key::V,
+    Key::W,
+    Key::X,
+    Key::Y,
+    Key::Z,
+    Key::Minus,
+    Key::Equals,
+    Key::LeftBracket,
+    Key::RightBracket,
+    Key::Backslash,
+    Key::Semicolon,
+    Key::Quote,
+    Key::Backquote,
+    Key::Comma,
+    Key::Period,
+    Key::Slash,
+];
+
+/// Converts keys to characters for a keyboard layout.
+pub trait KeyboardLayout {
+    /// Returns the character typed by a key with the modifier keys held down.
+    fn to_char(&self, key: Key, modifiers: ModifierKey) -> Option<char>;
+
+    /// Returns the key and modifier keys that type a character,
+    /// e.g. for typing text automatically.
+    ///
+    /// When several keys type the character, keys with fewer modifiers are preferred.
+    fn key_for_char(&self, c: char) -> Option<(Key, ModifierKey)> {
+        [
+            ModifierKey::NO_MODIFIER,
+            ModifierKey::SHIFT,
+            ModifierKey::ALT,
+        ]
+        .iter()
+        .flat_map(|&modifiers| CHAR_KEYS.iter().map(move |&key| (key, modifiers)))
+        .find(|&(key, modifiers)| self.to_char(key, modifiers) == Some(c))
+    }
+}
+
+/// The US QWERTY keyboard layout.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UsQwerty;
+
+/// The German QWERTZ keyboard layout.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct German;
+
+/// The French AZERTY keyboard layout.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct French;
+
+/// Picks the level of a key from the modifier keys.
+///
+/// Returns `None` for modifier keys used in shortcuts.
+fn level(modifiers: ModifierKey) -> Option<usize> {
+    if modifiers.intersects(ModifierKey::CTRL | ModifierKey::GUI) {
+        return None;
+    }
+    match (
+        modifiers.contains(ModifierKey::SHIFT),
+        modifiers.contains(ModifierKey::ALT),
+    ) {
+        (false, false) => Some(0),
+        (true, false) => Some(1),
+        (false, true) => Some(2),
+        (true, true) => None,
+    }
+}
+
+/// Returns the character of a letter key on a level, using the letter of the key.
+fn letter(key: Key, level: usize) -> Option<char> {
+    if Key::A <= key && key <= Key::Z {
+        let c = key as u8 as char;
+        match level {
+            0 => Some(c),
+            1 => Some(c.to_ascii_uppercase()),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Returns the character of a row on a level.
+fn pick(row: [char; 3], level: usize) -> Option<char> {
+    match row[level] {
+        NONE => None,
+        c => Some(c),
+    }
+}
+
+impl KeyboardLayout for UsQwerty {
+    fn to_char(&self, key: Key, modifiers: ModifierKey) -> Option<char> {
+        let level = level(modifiers)?;
+        let row = match key {
+            Key::Space => [' ', ' ', NONE],
+            Key::D1 => ['1', '!', NONE],
+            Key::D2 => ['2', '@', NONE],
+            Key::D3 => ['3', '#', NONE],
+            Key::D4 => ['4', '$', NONE],
+            Key::D5 => ['5', '%', NONE],
+            Key::D6 => ['6', '^', NONE],
+            Key::D7 => ['7', '&', NONE],
+            Key::D8 => ['8', '*', NONE],
+            Key::D9 => ['9', '(', NONE],
+            Key::D0 => ['0', ')', NONE],
+            Key::Minus => ['-', '_', NONE],
+            Key::Equals => ['=', '+', NONE],
+            Key::LeftBracket => ['[', '{', NONE],
+            Key::RightBracket => [']', '}', NONE],
+            Key::Backslash => ['\\', '|', NONE],
+            Key::Semicolon => [';', ':', NONE],
+            Key::Quote => ['\'', '"', NONE],
+            Key::Backquote => ['`', '~', NONE],
+            Key::Comma => [',', '<', NONE],
+            Key::Period => ['.', '>', NONE],
+            Key::Slash => ['/', '?', NONE],
+            _ => return letter(key, level),
+        };
+        pick(row, level)
+    }
+}
+
+impl KeyboardLayout for German {
+    fn to_char(&self, key: Key, modifiers: ModifierKey) -> Option<char> {
+        let level = level(modifiers)?;
+        let row = match key {
+            Key::Space => [' ', ' ', NONE],
+            Key::D1 => ['1', '!', NONE],
+            Key::D2 => ['2', '"', '²'],
+            Key::D3 => ['3', '§', '³'],
+            Key::D4 => ['4', '$', NONE],
+            Key::D5 => ['5', '%', NONE],
+            Key::D6 => ['6', '&', NONE],
+            Key::D7 => ['7', '/', '{'],
+            Key::D8 => ['8', '(', '['],
+            Key::D9 => ['9', ')', ']'],
+            Key::D0 => ['0', '=', '}'],
+            Key::Minus => ['ß', '?', '\\'],
+            Key::Equals => [NONE, NONE, NONE],
+            Key::LeftBracket => ['ü', 'Ü', NONE],
+            Key::RightBracket => ['+', '*', '~'],
+            Key::Backslash => ['#', '\'', NONE],
+            Key::Semicolon => ['ö', 'Ö', NONE],
+            Key::Quote => ['ä', 'Ä', NONE],
+            Key::Backquote => [NONE, '°', NONE],
+            Key::Comma => [',', ';', NONE],
+            Key::Period => ['.', ':', NONE],
+            Key::Slash => ['-', '_', NONE],
+            Key::Q => ['q', 'Q', '@'],
+            Key::E => ['e', 'E', '€'],
+            Key::M => ['m', 'M', 'µ'],
+            Key::Y => return letter(Key::Z, level),
+            Key::Z => return letter(Key::Y, level),
+            _ => return letter(key, level),
+        };
+        pick(row, level)
+    }
+}
+
+impl KeyboardLayout for French {
+    fn to_char(&self, key: Key, modifiers: ModifierKey) -> Option<char> {
+        let level = level(modifiers)?;
+        let row = match key {
+            Key::Space => [' ', ' ', NONE],
+            Key::D1 => ['&', '1', NONE],
+            Key::D2 => ['é', '2', NONE],
+            Key::D3 => ['"', '3', '#'],
+            Key::D4 => ['\'', '4', '{'],
+            Key::D5 => ['(', '5', '['],
+            Key::D6 => ['-', '6', '|'],
+            Key::D7 => ['è', '7', NONE],
+            Key::D8 => ['_', '8', '\\'],
+            Key::D9 => ['ç', '9', '^'],
+            Key::D0 => ['à', '0', '@'],
+            Key::Minus => [')', '°', ']'],
+            Key::Equals => ['=', '+', '}'],
+            Key::LeftBracket => [NONE, NONE, NONE],
+            Key::RightBracket => ['$', '£', '¤'],
+            Key::Backslash => ['*', 'µ', NONE],
+            Key::Semicolon => ['m', 'M', NONE],
+            Key::Quote => ['ù', '%', NONE],
+            Key::Backquote => ['²', NONE, NONE],
+            Key::M => [',', '?', NONE],
+            Key::Comma => [';', '.', NONE],
+            Key::Period => [':', '/', NONE],
+            Key::Slash => ['!', '§', NONE],
+            Key::E => ['e', 'E', '€'],
+            Key::A => return letter(Key::Q, level),
+            Key::Q => return letter(Key::A, level),
+            Key::W => return letter(Key::Z, level),
+            Key::Z => return letter(Key::W, level),
+            _ => return letter(key, level),
+        };
+        pick(row, level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_us_qwerty() {
+        assert_eq!(
+            UsQwerty.to_char(Key::A, ModifierKey::NO_MODIFIER),
+            Some('a')
+        );
+        assert_eq!(UsQwerty.to_char(Key::A, ModifierKey::SHIFT), Some('A'));
+        assert_eq!(UsQwerty.to_char(Key::D1, ModifierKey::SHIFT), Some('!'));
+        assert_eq!(UsQwerty.to_char(Key::A, ModifierKey::CTRL), None);
+        assert_eq!(
+            UsQwerty.to_char(Key::Return, ModifierKey::NO_MODIFIER),
+            None
+        );
+    }
+
+    #[test]
+    fn test_key_for_char() {
+        assert_eq!(
+            UsQwerty.key_for_char('!'),
+            Some((Key::D1, ModifierKey::SHIFT))
+        );
+        assert_eq!(
+            UsQwerty.key_for_char('a'),
+            Some((Key::A, ModifierKey::NO_MODIFIER))
+        );
+        assert_eq!(UsQwerty.key_for_char('€'), None);
+        assert_eq!(German.key_for_char('@'), Some((Key::Q, ModifierKey::ALT)));
+    }
+
+    #[test]
+    fn test_german() {
+        assert_eq!(
+            UsQwerty.to_char(Key::Z, ModifierKey::NO_MODIFIER),
+            Some('z')
+        );
+        assert_eq!(German.to_char(Key::Z, ModifierKey::NO_MODIFIER), Some('y'));
+        assert_eq!(German.to_char(Key::Y, ModifierKey::SHIFT), Some('Z'));
+        assert_eq!(German.to_char(Key::Q, ModifierKey::ALT), Some('@'));
+        assert_eq!(
+            German.to_char(Key::Semicolon, ModifierKey::NO_MODIFIER),
+            Some('ö')
+        );
+        assert_eq!(German.to_char(Key::Equals, ModifierKey::NO_MODIFIER), None);
+    }
+
+    #[test]
+    fn test_french() {
+        assert_eq!(French.to_char(Key::Q, ModifierKey::NO_MODIFIER), Some('a'));
+        assert_eq!(
+            French.to_char(Key::Semicolon, ModifierKey::SHIFT),
+            Some('M')
+        );
+        assert_eq!(French.to_char(Key::D2, ModifierKey::NO_MODIFIER), Some('é'));
+        assert_eq!(French.to_char(Key::D2, ModifierKey::SHIFT), Some('2'));
+    }
+}
@@ -14,49 +14,98 @@
 
 //! A flexible structure for user interactions
 //! to be used in window frameworks and widgets libraries.
+//!
+//! The event types and the [`GenericEvent`](./generic_event/trait.GenericEvent.html) trait
+//! do not depend on a window or an event loop,
+//! which live in the separate `pistoncore-window` and `pistoncore-event_loop` crates.
+//! This makes it possible to use the event types on their own, e.g. for input remapping.
+//!
+//! This crate requires `std` and does not support `no_std`:
+//! File drag events carry a `PathBuf`,
+//! viewports come from `piston-viewport`, which requires `std`,
+//! and input helpers use floating point functions of `std`.
 
 #[macro_use]
 extern crate bitflags;
 #[macro_use]
 extern crate serde_derive;
 
-use std::{any::Any, cmp::Ordering, fmt, path::PathBuf, sync::Arc};
+use std::{any::Any, cmp::Ordering, convert::TryFrom, fmt, path::PathBuf, sync::Arc};
 
 pub use controller::{ControllerAxisArgs, ControllerButton, ControllerHat};
 pub use keyboard::Key;
-pub use mouse::MouseButton;
+pub use mouse::{MouseButton, ScrollUnit};
 
 pub mod controller;
 pub mod keyboard;
 pub mod mouse;
 
 pub use after_render::{AfterRenderArgs, AfterRenderEvent};
+pub use axis_button::AxisButton;
 pub use button::{ButtonArgs, ButtonEvent, ButtonState, PressEvent, ReleaseEvent};
+pub use button_edges::ButtonEdges;
+pub use click_tracker::ClickTracker;
 pub use close::{CloseArgs, CloseEvent};
 pub use controller::ControllerAxisEvent;
 pub use cursor::CursorEvent;
+pub use drag_controller::{DragAction, DragAxis, DragController};
 use event_id::EventId;
-pub use focus::FocusEvent;
-pub use generic_event::GenericEvent;
+pub use focus::{FocusArgs, FocusEvent};
+pub use generic_event::{DynEvent, GenericEvent};
+pub use hidpi::{ScaleFactorChangedArgs, ScaleFactorChangedEvent};
+pub use hold_tracker::HoldTracker;
 pub use idle::{IdleArgs, IdleEvent};
+pub use input_snapshot::InputSnapshot;
+pub use key_map::KeyMap;
+pub use key_repeat::KeyRepeat;
+pub use key_rollover::KeyRollover;
+pub use layout::KeyboardLayout;
+pub use modified_scroll::ModifiedScroll;
+pub use modifier_tap::ModifierTap;
 pub use mouse::{MouseCursorEvent, MouseRelativeEvent, MouseScrollEvent};
+pub use mouse_delta::MouseDelta;
+pub use movement_axis::MovementAxis;
 pub use render::{RenderArgs, RenderEvent};
 pub use resize::{ResizeArgs, ResizeEvent};
+pub use resize_controller::ResizeController;
+pub use scroll_inertia::ScrollInertia;
+pub use slow_update::{SlowUpdateArgs, SlowUpdateEvent};
+pub use stuck_key_detector::{StuckKeyDetector, StuckKeyWarning};
 pub use text::TextEvent;
 pub use touch::{Touch, TouchArgs, TouchEvent};
 pub use update::{UpdateArgs, UpdateEvent};
 
 pub mod event_id;
 pub mod generic_event;
+pub mod layout;
+pub mod prelude;
 
 mod after_render;
+mod axis_button;
 mod button;
+mod button_edges;
+mod click_tracker;
 mod close;
 mod cursor;
+mod drag_controller;
 mod focus;
+mod hidpi;
+mod hold_tracker;
 mod idle;
+mod input_snapshot;
+mod key_map;
+mod key_repeat;
+mod key_rollover;
+mod modified_scroll;
+mod modifier_tap;
+mod mouse_delta;
+mod movement_axis;
 mod render;
 mod resize;
+mod resize_controller;
+mod scroll_inertia;
+mod slow_update;
+mod stuck_key_detector;
 mod text;
 mod touch;
 mod update;
@@ -86,12 +135,14 @@ pub enum Motion {
     MouseCursor([f64; 2]),
     /// Position in relative coordinates.
     MouseRelative([f64; 2]),
-    /// Position in scroll ticks.
+    /// Scroll amount in lines, or scroll ticks.
     MouseScroll([f64; 2]),
     /// Controller axis move event.
     ControllerAxis(ControllerAxisArgs),
     /// Touch event.
     Touch(TouchArgs),
+    /// Scroll amount in pixels, e.g. from a touchpad.
+    MouseScrollPixels([f64; 2]),
 }
 
 /// Stores controller hat state.
@@ -139,6 +190,8 @@ pub enum Input {
     Text(String),
     /// Window got resized.
     Resize(ResizeArgs),
+    /// Window scale factor changed.
+    ScaleFactorChanged(ScaleFactorChangedArgs),
     /// Window gained or lost focus.
     Focus(bool),
     /// Window gained or lost cursor.
@@ -160,6 +213,8 @@ pub enum Loop {
     Update(UpdateArgs),
     /// Do background tasks that can be done incrementally.
     Idle(IdleArgs),
+    /// Update the state of the application at a lower rate.
+    SlowUpdate(SlowUpdateArgs),
 }
 
 /// Models all events.
@@ -183,6 +238,23 @@ pub enum Event {
     Custom(EventId, Arc<dyn Any + Send + Sync>, Option<TimeStamp>),
 }
 
+impl Event {
+    /// Returns true if this is an input event.
+    pub fn is_input(&self) -> bool {
+        matches!(self, Event::Input(_, _))
+    }
+
+    /// Returns true if this is an update event.
+    pub fn is_update(&self) -> bool {
+        matches!(self, Event::Loop(Loop::Update(_)))
+    }
+
+    /// Returns true if this is a render event.
+    pub fn is_render(&self) -> bool {
+        matches!(self, Event::Loop(Loop::Render(_)))
+    }
+}
+
 impl fmt::Debug for Event {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -198,8 +270,8 @@ impl PartialEq for Event {
         use Event::*;
 
         match (self, other) {
-            (&Input(ref a, _), &Input(ref b, _)) => a == b,
-            (&Loop(ref a), &Loop(ref b)) => a == b,
+            (Input(a, _), Input(b, _)) => a == b,
+            (Loop(a), Loop(b)) => a == b,
             (_, _) => false,
         }
     }
@@ -210,9 +282,9 @@ impl PartialOrd for Event {
         use Event::*;
 
         match (self, other) {
-            (&Input(ref a, _), &Input(ref b, _)) => a.partial_cmp(b),
-            (&Loop(ref a), &Loop(ref b)) => a.partial_cmp(b),
-            (&Custom(ref a_id, _, _), &Custom(ref b_id, _, _)) => {
+            (Input(a, _), Input(b, _)) => a.partial_cmp(b),
+            (Loop(a), Loop(b)) => a.partial_cmp(b),
+            (Custom(a_id, _, _), Custom(b_id, _, _)) => {
                 let res = a_id.partial_cmp(b_id);
                 if res == Some(Ordering::Equal) {
                     None
@@ -318,6 +390,18 @@ impl From<UpdateArgs> for Event {
     }
 }
 
+impl From<SlowUpdateArgs> for Loop {
+    fn from(args: SlowUpdateArgs) -> Self {
+        Loop::SlowUpdate(args)
+    }
+}
+
+impl From<SlowUpdateArgs> for Event {
+    fn from(args: SlowUpdateArgs) -> Self {
+        Event::Loop(Loop::SlowUpdate(args))
+    }
+}
+
 impl From<IdleArgs> for Loop {
     fn from(args: IdleArgs) -> Self {
         Loop::Idle(args)
@@ -330,6 +414,12 @@ impl From<IdleArgs> for Event {
     }
 }
 
+impl From<ScaleFactorChangedArgs> for Input {
+    fn from(args: ScaleFactorChangedArgs) -> Self {
+        Input::ScaleFactorChanged(args)
+    }
+}
+
 impl From<CloseArgs> for Input {
     fn from(args: CloseArgs) -> Self {
         Input::Close(args)
@@ -370,6 +460,34 @@ impl From<Event> for Option<Input> {
     }
 }
 
+/// Returns the event as error if it is not an input event.
+///
+/// The time stamp of the input event is discarded.
+impl TryFrom<Event> for Input {
+    type Error = Event;
+
+    fn try_from(event: Event) -> Result<Input, Event> {
+        if let Event::Input(input, _) = event {
+            Ok(input)
+        } else {
+            Err(event)
+        }
+    }
+}
+
+/// Returns the event as error if it is not a loop event.
+impl TryFrom<Event> for Loop {
+    type Error = Event;
+
+    fn try_from(event: Event) -> Result<Loop, Event> {
+        if let Event::Loop(l) = event {
+            Ok(l)
+        } else {
+            Err(event)
+        }
+    }
+}
+
 impl From<Event> for Option<Loop> {
     fn from(event: Event) -> Option<Loop> {
         if let Event::Loop(l) = event {
@@ -392,4 +510,60 @@ mod tests {
         chk::<Loop>();
         chk::<Event>();
     }
+
+    #[test]
+    fn test_input_event_conversion() {
+        let input = Input::Button(ButtonArgs::new(ButtonState::Press, Key::A.into()));
+        let e = Event::from(input.clone());
+        assert_eq!(Input::try_from(e), Ok(input));
+
+        let e: Event = UpdateArgs { dt: 0.0 }.into();
+        assert_eq!(Input::try_from(e.clone()), Err(e.clone()));
+        assert_eq!(Loop::try_from(e), Ok(Loop::Update(UpdateArgs { dt: 0.0 })));
+    }
+
+    #[test]
+    fn test_event_predicates() {
+        let input: Event = Input::Focus(true).into();
+        let update: Event = UpdateArgs { dt: 0.0 }.into();
+        let render: Event = RenderArgs {
+            ext_dt: 0.0,
+            window_size: [0.0, 0.0],
+            draw_size: [0, 0],
+        }
+        .into();
+
+        assert!(input.is_input());
+        assert!(!input.is_update());
+        assert!(!input.is_render());
+
+        assert!(!update.is_input());
+        assert!(update.is_update());
+        assert!(!update.is_render());
+
+        assert!(!render.is_input());
+        assert!(!render.is_update());
+        assert!(render.is_render());
+    }
+
+    #[test]
+    fn test_button_hash_map() {
+        use std::collections::HashMap;
+
+        let mut bindings = HashMap::new();
+        bindings.insert(Button::Keyboard(Key::Space), "jump");
+        bindings.insert(Button::Mouse(MouseButton::Left), "shoot");
+        bindings.insert(Button::Controller(ControllerButton::new(0, 1)), "reload");
+
+        assert_eq!(bindings.get(&Button::Keyboard(Key::Space)), Some(&"jump"));
+        assert_eq!(
+            bindings.get(&Button::Mouse(MouseButton::Left)),
+            Some(&"shoot")
+        );
+        assert_eq!(
+            bindings.get(&Button::Controller(ControllerButton::new(0, 1))),
+            Some(&"reload")
+        );
+        assert_eq!(bindings.get(&Button::Mouse(MouseButton::Right)), None);
+    }
 }
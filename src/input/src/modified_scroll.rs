@@ -0,0 +1,52 @@
+use crate::{keyboard::ModifierKey, GenericEvent};
+
+/// Tags mouse scroll events with the modifier keys held down,
+/// e.g. to zoom when scrolling with Ctrl held down.
+///
+/// Modifier keys are released when the window loses focus.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModifiedScroll {
+    modifiers: ModifierKey,
+}
+
+impl ModifiedScroll {
+    /// Creates a new modified scroll helper without any modifier keys held down.
+    pub fn new() -> ModifiedScroll {
+        ModifiedScroll::default()
+    }
+
+    /// Returns the modifier keys held down.
+    pub fn modifiers(&self) -> ModifierKey {
+        self.modifiers
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the modifier keys held down and the scroll amount
+    /// when receiving a mouse scroll event.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<(ModifierKey, [f64; 2])> {
+        self.modifiers.event(e);
+        e.mouse_scroll_args().map(|scroll| (self.modifiers, scroll))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event, Key, Motion};
+
+    #[test]
+    fn test_ctrl_scroll() {
+        let mut scroll = ModifiedScroll::new();
+        let up: Event = Motion::MouseScroll([0.0, 1.0]).into();
+        assert_eq!(
+            scroll.event(&up),
+            Some((ModifierKey::NO_MODIFIER, [0.0, 1.0]))
+        );
+        let ctrl: Event = ButtonArgs::new(ButtonState::Press, Key::LCtrl.into()).into();
+        assert_eq!(scroll.event(&ctrl), None);
+        let (modifiers, amount) = scroll.event(&up).unwrap();
+        assert!(modifiers.contains(ModifierKey::CTRL));
+        assert_eq!(amount, [0.0, 1.0]);
+    }
+}
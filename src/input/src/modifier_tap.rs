@@ -0,0 +1,72 @@
+use crate::{Button, GenericEvent, Key};
+
+/// Detects taps of a modifier key on its own,
+/// e.g. pressing and releasing Alt to open a menu.
+///
+/// A tap is a press and release of a modifier key
+/// without any other button being pressed in between.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModifierTap {
+    pressed: Option<Key>,
+}
+
+impl ModifierTap {
+    /// Creates a new modifier tap detector.
+    pub fn new() -> ModifierTap {
+        ModifierTap { pressed: None }
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the modifier key when it is released after a tap.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<Key> {
+        if let Some(button) = e.press_args() {
+            self.pressed = match button {
+                Button::Keyboard(
+                    key @ (Key::LCtrl
+                    | Key::RCtrl
+                    | Key::LShift
+                    | Key::RShift
+                    | Key::LAlt
+                    | Key::RAlt
+                    | Key::LGui
+                    | Key::RGui),
+                ) if self.pressed.is_none() => Some(key),
+                _ => None,
+            };
+        }
+        if let Some(Button::Keyboard(key)) = e.release_args() {
+            if self.pressed == Some(key) {
+                self.pressed = None;
+                return Some(key);
+            }
+        }
+        if let Some(false) = e.focus_args() {
+            self.pressed = None;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event};
+
+    #[test]
+    fn test_modifier_tap() {
+        let alt_press: Event = ButtonArgs::new(ButtonState::Press, Key::LAlt.into()).into();
+        let alt_release: Event = ButtonArgs::new(ButtonState::Release, Key::LAlt.into()).into();
+        let mut tap = ModifierTap::new();
+        assert_eq!(tap.event(&alt_press), None);
+        assert_eq!(tap.event(&alt_release), Some(Key::LAlt));
+
+        // Alt+S is a shortcut, not a tap.
+        let s_press: Event = ButtonArgs::new(ButtonState::Press, Key::S.into()).into();
+        let s_release: Event = ButtonArgs::new(ButtonState::Release, Key::S.into()).into();
+        assert_eq!(tap.event(&alt_press), None);
+        assert_eq!(tap.event(&s_press), None);
+        assert_eq!(tap.event(&s_release), None);
+        assert_eq!(tap.event(&alt_release), None);
+    }
+}
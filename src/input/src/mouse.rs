@@ -72,6 +72,25 @@ mod mouse_button_tests {
     }
 }
 
+/// The unit of mouse scroll events.
+///
+/// Some window back-ends report scrolling in lines, e.g. for a mouse wheel,
+/// while others report scrolling in pixels, e.g. for a touchpad.
+/// Scrolling in lines is reported by `Motion::MouseScroll`,
+/// and scrolling in pixels by `Motion::MouseScrollPixels`.
+///
+/// Defaults to `ScrollUnit::Line` for window back-ends that do not specify the unit.
+#[derive(
+    Copy, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Ord, PartialOrd, Hash, Debug,
+)]
+pub enum ScrollUnit {
+    /// Scrolling in lines, or scroll ticks.
+    #[default]
+    Line,
+    /// Scrolling in pixels.
+    Pixel,
+}
+
 /// The position of the mouse cursor.
 pub trait MouseCursorEvent: Sized {
     /// Creates a mouse cursor event.
@@ -158,8 +177,19 @@ impl MouseRelativeEvent for Event {
 pub trait MouseScrollEvent: Sized {
     /// Creates a mouse scroll event.
     ///
+    /// Preserves scroll unit from original scroll event, if any,
+    /// otherwise it uses `ScrollUnit::Line`.
     /// Preserves time stamp from original input event, if any.
     fn from_pos(pos: [f64; 2], old_event: &Self) -> Option<Self>;
+    /// Creates a mouse scroll event with a scroll unit.
+    ///
+    /// Preserves time stamp from original input event, if any.
+    ///
+    /// The default implementation ignores the unit and calls `from_pos`.
+    fn from_scroll(pos: [f64; 2], unit: ScrollUnit, old_event: &Self) -> Option<Self> {
+        let _ = unit;
+        MouseScrollEvent::from_pos(pos, old_event)
+    }
     /// Calls a closure if this is a mouse scroll event.
     fn mouse_scroll<U, F>(&self, f: F) -> Option<U>
     where
@@ -168,19 +198,31 @@ pub trait MouseScrollEvent: Sized {
     fn mouse_scroll_args(&self) -> Option<[f64; 2]> {
         self.mouse_scroll(|pos| pos)
     }
+    /// Returns the scroll unit if this is a mouse scroll event.
+    ///
+    /// The default implementation returns `ScrollUnit::Line` for any scroll event.
+    fn mouse_scroll_unit(&self) -> Option<ScrollUnit> {
+        self.mouse_scroll(|_| ScrollUnit::Line)
+    }
 }
 
 impl MouseScrollEvent for Event {
     fn from_pos(pos: [f64; 2], old_event: &Self) -> Option<Self> {
+        let unit = old_event.mouse_scroll_unit().unwrap_or_default();
+        MouseScrollEvent::from_scroll(pos, unit, old_event)
+    }
+
+    fn from_scroll(pos: [f64; 2], unit: ScrollUnit, old_event: &Self) -> Option<Self> {
         let timestamp = if let Event::Input(_, x) = old_event {
             *x
         } else {
             None
         };
-        Some(Event::Input(
-            Input::Move(Motion::MouseScroll(pos)),
-            timestamp,
-        ))
+        let motion = match unit {
+            ScrollUnit::Line => Motion::MouseScroll(pos),
+            ScrollUnit::Pixel => Motion::MouseScrollPixels(pos),
+        };
+        Some(Event::Input(Input::Move(motion), timestamp))
     }
 
     fn mouse_scroll<U, F>(&self, mut f: F) -> Option<U>
@@ -189,6 +231,15 @@ impl MouseScrollEvent for Event {
     {
         match *self {
             Event::Input(Input::Move(Motion::MouseScroll(pos)), _) => Some(f(pos)),
+            Event::Input(Input::Move(Motion::MouseScrollPixels(pos)), _) => Some(f(pos)),
+            _ => None,
+        }
+    }
+
+    fn mouse_scroll_unit(&self) -> Option<ScrollUnit> {
+        match *self {
+            Event::Input(Input::Move(Motion::MouseScroll(_)), _) => Some(ScrollUnit::Line),
+            Event::Input(Input::Move(Motion::MouseScrollPixels(_)), _) => Some(ScrollUnit::Pixel),
             _ => None,
         }
     }
@@ -239,4 +290,50 @@ mod mouse_event_tests {
             .unwrap();
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn test_input_mouse_scroll_unit() {
+        use super::super::Motion;
+
+        for &(motion, unit) in &[
+            (Motion::MouseScroll([0.0, 0.0]), ScrollUnit::Line),
+            (Motion::MouseScrollPixels([0.0, 0.0]), ScrollUnit::Pixel),
+        ] {
+            let e: Event = motion.into();
+            let a: Option<Event> = MouseScrollEvent::from_pos([1.0, 2.0], &e);
+            assert_eq!(a.as_ref().unwrap().mouse_scroll_unit(), Some(unit));
+            assert_eq!(a.as_ref().unwrap().mouse_scroll_args(), Some([1.0, 2.0]));
+            let b: Option<Event> = MouseScrollEvent::from_scroll([1.0, 2.0], unit, &e);
+            assert_eq!(a, b);
+        }
+
+        let e: Event = Motion::MouseCursor([0.0, 0.0]).into();
+        let a: Option<Event> = MouseScrollEvent::from_pos([1.0, 0.0], &e);
+        assert_eq!(a.unwrap().mouse_scroll_unit(), Some(ScrollUnit::Line));
+    }
+
+    #[test]
+    fn test_mouse_scroll_defaults() {
+        // Implements only the methods required before scroll units were added.
+        #[derive(Debug, PartialEq)]
+        struct Scroll([f64; 2]);
+
+        impl MouseScrollEvent for Scroll {
+            fn from_pos(pos: [f64; 2], _old_event: &Self) -> Option<Self> {
+                Some(Scroll(pos))
+            }
+
+            fn mouse_scroll<U, F>(&self, mut f: F) -> Option<U>
+            where
+                F: FnMut([f64; 2]) -> U,
+            {
+                Some(f(self.0))
+            }
+        }
+
+        let e = Scroll([0.0, 0.0]);
+        let a = MouseScrollEvent::from_scroll([1.0, 2.0], ScrollUnit::Pixel, &e);
+        assert_eq!(a, Some(Scroll([1.0, 2.0])));
+        assert_eq!(e.mouse_scroll_unit(), Some(ScrollUnit::Line));
+    }
 }
@@ -0,0 +1,95 @@
+use crate::GenericEvent;
+
+/// Filters mouse relative motion while the cursor is captured,
+/// e.g. for a first-person camera.
+///
+/// When cursor capture is enabled, or the window gains focus,
+/// the first relative motion might be the distance from the old cursor position
+/// to the window center. This helper discards that motion to avoid a sudden jump.
+/// Optionally, large motions can be clamped to a maximum magnitude.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MouseDelta {
+    /// The maximum magnitude of relative motion, if any.
+    ///
+    /// Larger motions are scaled down to this magnitude.
+    pub max_delta: Option<f64>,
+    skip_next: bool,
+}
+
+impl MouseDelta {
+    /// Creates a new mouse delta filter without a maximum magnitude.
+    pub fn new() -> MouseDelta {
+        MouseDelta {
+            max_delta: None,
+            skip_next: false,
+        }
+    }
+
+    /// Sets the maximum magnitude of relative motion.
+    pub fn max_delta(mut self, value: f64) -> Self {
+        self.max_delta = Some(value);
+        self
+    }
+
+    /// Tells the filter that cursor capture was enabled.
+    ///
+    /// The next relative motion is discarded.
+    pub fn capture(&mut self) {
+        self.skip_next = true;
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the filtered relative motion if this is a mouse relative event.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<[f64; 2]> {
+        if let Some(true) = e.focus_args() {
+            self.skip_next = true;
+        }
+        let pos = e.mouse_relative_args()?;
+        if self.skip_next {
+            self.skip_next = false;
+            return None;
+        }
+        match self.max_delta {
+            Some(max) => {
+                let len = pos[0].hypot(pos[1]);
+                if len > max {
+                    Some([pos[0] * max / len, pos[1] * max / len])
+                } else {
+                    Some(pos)
+                }
+            }
+            None => Some(pos),
+        }
+    }
+}
+
+impl Default for MouseDelta {
+    fn default() -> MouseDelta {
+        MouseDelta::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Event, Motion};
+
+    #[test]
+    fn test_mouse_delta_capture() {
+        let mut delta = MouseDelta::new();
+        delta.capture();
+        let jump: Event = Motion::MouseRelative([400.0, 300.0]).into();
+        let normal: Event = Motion::MouseRelative([2.0, -1.0]).into();
+        assert_eq!(delta.event(&jump), None);
+        assert_eq!(delta.event(&normal), Some([2.0, -1.0]));
+        assert_eq!(delta.event(&normal), Some([2.0, -1.0]));
+    }
+
+    #[test]
+    fn test_mouse_delta_max() {
+        let mut delta = MouseDelta::new().max_delta(5.0);
+        let jump: Event = Motion::MouseRelative([30.0, 40.0]).into();
+        assert_eq!(delta.event(&jump), Some([3.0, 4.0]));
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{Button, GenericEvent, Key};
+
+/// Converts four direction buttons to a movement vector,
+/// e.g. WASD or arrow keys for moving a character.
+///
+/// The x axis points right and the y axis points up,
+/// so negate y for window coordinates.
+/// The vector has length 1 when moving diagonally,
+/// so diagonal movement is not faster.
+/// Opposite directions held at the same time cancel out.
+/// Held buttons are released when the window loses focus.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MovementAxis {
+    /// The button for moving up.
+    pub up: Button,
+    /// The button for moving down.
+    pub down: Button,
+    /// The button for moving left.
+    pub left: Button,
+    /// The button for moving right.
+    pub right: Button,
+    held: [bool; 4],
+}
+
+impl MovementAxis {
+    /// Creates a new movement axis with buttons for up, down, left and right.
+    pub fn new(up: Button, down: Button, left: Button, right: Button) -> MovementAxis {
+        MovementAxis {
+            up,
+            down,
+            left,
+            right,
+            held: [false; 4],
+        }
+    }
+
+    /// Creates a new movement axis using the W, A, S and D keys.
+    pub fn wasd() -> MovementAxis {
+        MovementAxis::new(Key::W.into(), Key::S.into(), Key::A.into(), Key::D.into())
+    }
+
+    /// Creates a new movement axis using the arrow keys.
+    pub fn arrows() -> MovementAxis {
+        MovementAxis::new(
+            Key::Up.into(),
+            Key::Down.into(),
+            Key::Left.into(),
+            Key::Right.into(),
+        )
+    }
+
+    /// Returns the movement vector, with length 0 or 1.
+    pub fn vector(&self) -> [f64; 2] {
+        let axis = |positive: bool, negative: bool| positive as i8 as f64 - negative as i8 as f64;
+        let [up, down, left, right] = self.held;
+        let x = axis(right, left);
+        let y = axis(up, down);
+        let len = x.hypot(y);
+        if len == 0.0 {
+            [0.0, 0.0]
+        } else {
+            [x / len, y / len]
+        }
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the new movement vector when it changes.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<[f64; 2]> {
+        let old = self.vector();
+        let buttons = [self.up, self.down, self.left, self.right];
+        if let Some(button) = e.press_args() {
+            for (held, &b) in self.held.iter_mut().zip(&buttons) {
+                if b == button {
+                    *held = true;
+                }
+            }
+        }
+        if let Some(button) = e.release_args() {
+            for (held, &b) in self.held.iter_mut().zip(&buttons) {
+                if b == button {
+                    *held = false;
+                }
+            }
+        }
+        if let Some(false) = e.focus_args() {
+            self.held = [false; 4];
+        }
+        let new = self.vector();
+        if new != old {
+            Some(new)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for MovementAxis {
+    fn default() -> MovementAxis {
+        MovementAxis::wasd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event, Input};
+
+    #[test]
+    fn test_movement_diagonal() {
+        let mut movement = MovementAxis::wasd();
+        assert_eq!(
+            movement.event(&Event::from(ButtonArgs::new(
+                ButtonState::Press,
+                Key::W.into()
+            ))),
+            Some([0.0, 1.0])
+        );
+        let [x, y] = movement
+            .event(&Event::from(ButtonArgs::new(
+                ButtonState::Press,
+                Key::D.into(),
+            )))
+            .unwrap();
+        assert!((x - 0.5f64.sqrt()).abs() < 1e-9);
+        assert!((y - 0.5f64.sqrt()).abs() < 1e-9);
+        assert_eq!(
+            movement.event(&Event::from(ButtonArgs::new(
+                ButtonState::Press,
+                Key::A.into()
+            ))),
+            Some([0.0, 1.0])
+        );
+        assert_eq!(
+            movement.event(&Event::from(Input::Focus(false))),
+            Some([0.0, 0.0])
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Imports all event traits at once.
+//!
+//! Calling a typed accessor such as `press_args` or `render_args` on an event
+//! requires the corresponding trait to be in scope.
+//! This module re-exports all event traits, so they can be imported with a single line:
+//!
+//! ```
+//! use input::prelude::*;
+//! ```
+
+pub use crate::{
+    AfterRenderEvent, ButtonEvent, CloseEvent, ControllerAxisEvent, CursorEvent, FocusEvent,
+    GenericEvent, IdleEvent, MouseCursorEvent, MouseRelativeEvent, MouseScrollEvent, PressEvent,
+    ReleaseEvent, RenderEvent, ResizeEvent, ScaleFactorChangedEvent, SlowUpdateEvent, TextEvent,
+    TouchEvent, UpdateEvent,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Button, ButtonArgs, ButtonState, Event, Key, RenderArgs, UpdateArgs};
+
+    #[test]
+    fn test_prelude() {
+        let e: Event = ButtonArgs::new(ButtonState::Press, Key::A.into()).into();
+        assert_eq!(e.press_args(), Some(Button::Keyboard(Key::A)));
+
+        let args = RenderArgs {
+            ext_dt: 0.0,
+            window_size: [10.0, 10.0],
+            draw_size: [10, 10],
+        };
+        let e: Event = args.into();
+        assert_eq!(e.render_args(), Some(args));
+
+        let e: Event = UpdateArgs { dt: 0.5 }.into();
+        assert_eq!(e.update_args(), Some(UpdateArgs { dt: 0.5 }));
+    }
+}
@@ -22,6 +22,18 @@ impl RenderArgs {
             draw_size: self.draw_size,
         }
     }
+
+    /// Returns the aspect ratio of the window, width divided by height.
+    ///
+    /// Returns `1.0` when the height is zero, to avoid producing infinity or NaN.
+    pub fn aspect_ratio(&self) -> f64 {
+        let [w, h] = self.window_size;
+        if h == 0.0 {
+            1.0
+        } else {
+            w / h
+        }
+    }
 }
 
 /// When the next frame should be rendered.
@@ -83,4 +95,32 @@ mod tests {
             .unwrap();
         assert_eq!(x, y);
     }
+
+    #[test]
+    fn test_input_render_direct() {
+        use crate::UpdateArgs;
+
+        let args = RenderArgs {
+            ext_dt: 1.0,
+            window_size: [10.0, 10.0],
+            draw_size: [10, 10],
+        };
+        let e: Event = UpdateArgs { dt: 0.0 }.into();
+        let x: Option<Event> = RenderEvent::from_render_args(&args, &e);
+        let y: Event = args.into();
+        assert_eq!(x, Some(y));
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        let mut args = RenderArgs {
+            ext_dt: 0.0,
+            window_size: [1920.0, 1080.0],
+            draw_size: [1920, 1080],
+        };
+        assert!((args.aspect_ratio() - 16.0 / 9.0).abs() < 1e-9);
+
+        args.window_size = [1920.0, 0.0];
+        assert_eq!(args.aspect_ratio(), 1.0);
+    }
 }
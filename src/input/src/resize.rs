@@ -20,9 +20,44 @@ impl ResizeArgs {
             draw_size: self.draw_size,
         }
     }
+
+    /// Returns the largest centered rectangle `[x, y, width, height]` in points
+    /// with an aspect ratio (width / height) that fits inside the window.
+    ///
+    /// The rest of the window is left for letterbox or pillarbox bars.
+    /// Returns the whole window when the aspect ratio is not positive and finite,
+    /// to avoid producing infinity or NaN.
+    pub fn letterbox_viewport(&self, aspect: f64) -> [f64; 4] {
+        let [w, h] = self.window_size;
+        if !(aspect.is_finite() && aspect > 0.0) {
+            return [0.0, 0.0, w, h];
+        }
+        let (width, height) = if w / h > aspect {
+            (h * aspect, h)
+        } else {
+            (w, w / aspect)
+        };
+        [(w - width) / 2.0, (h - height) / 2.0, width, height]
+    }
+
+    /// Returns `true` if the window or draw size has a zero dimension.
+    ///
+    /// Some window back-ends report a zero size when the window is minimized.
+    /// Where `Window::is_minimized` is not available, this is the signal to use
+    /// for skipping layout, e.g. to avoid dividing by the size.
+    /// The event loop does not render while the window size is zero.
+    pub fn is_zero(&self) -> bool {
+        self.window_size[0] == 0.0
+            || self.window_size[1] == 0.0
+            || self.draw_size[0] == 0
+            || self.draw_size[1] == 0
+    }
 }
 
 /// When the window is resized.
+///
+/// Changes of the scale factor are reported by
+/// [`ScaleFactorChangedEvent`](trait.ScaleFactorChangedEvent.html).
 pub trait ResizeEvent: Sized {
     /// Creates a resize event.
     ///
@@ -80,4 +115,34 @@ mod tests {
             .unwrap();
         assert_eq!(x, y);
     }
+
+    #[test]
+    fn test_letterbox_viewport() {
+        let args = ResizeArgs {
+            window_size: [800.0, 600.0],
+            draw_size: [800, 600],
+        };
+        assert_eq!(
+            args.letterbox_viewport(16.0 / 9.0),
+            [0.0, 75.0, 800.0, 450.0]
+        );
+        for &aspect in &[0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(args.letterbox_viewport(aspect), [0.0, 0.0, 800.0, 600.0]);
+        }
+        assert_eq!(args.letterbox_viewport(1.0), [100.0, 0.0, 600.0, 600.0]);
+    }
+
+    #[test]
+    fn test_resize_is_zero() {
+        let minimized = ResizeArgs {
+            window_size: [100.0, 0.0],
+            draw_size: [100, 0],
+        };
+        assert!(minimized.is_zero());
+        let normal = ResizeArgs {
+            window_size: [100.0, 100.0],
+            draw_size: [100, 100],
+        };
+        assert!(!normal.is_zero());
+    }
 }
@@ -0,0 +1,106 @@
+use crate::{GenericEvent, ResizeArgs};
+
+/// Clamps and debounces resize events,
+/// e.g. to rebuild a layout only once after the user has finished resizing the window.
+///
+/// The window size is clamped to a minimum and maximum size in points,
+/// and the draw size is scaled by the same amount.
+/// A resize is emitted once no other resize has been received
+/// for the debounce interval, which is measured by update events.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ResizeController {
+    /// The minimum window size in points.
+    pub min_size: [f64; 2],
+    /// The maximum window size in points.
+    pub max_size: [f64; 2],
+    /// The time in seconds without resizing before a resize is emitted.
+    pub debounce: f64,
+    pending: Option<(ResizeArgs, f64)>,
+}
+
+impl ResizeController {
+    /// Creates a new resize controller with minimum and maximum size in points,
+    /// and debounce interval in seconds.
+    pub fn new(min_size: [f64; 2], max_size: [f64; 2], debounce: f64) -> ResizeController {
+        ResizeController {
+            min_size,
+            max_size,
+            debounce,
+            pending: None,
+        }
+    }
+
+    /// Returns `true` if a resize is waiting for the debounce interval to pass.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Clamps resize arguments to the minimum and maximum size.
+    pub fn clamp(&self, args: &ResizeArgs) -> ResizeArgs {
+        let mut window_size = args.window_size;
+        let mut draw_size = args.draw_size;
+        for i in 0..2 {
+            let size = window_size[i].max(self.min_size[i]).min(self.max_size[i]);
+            if window_size[i] > 0.0 {
+                draw_size[i] = (draw_size[i] as f64 * size / window_size[i]).round() as u32;
+            }
+            window_size[i] = size;
+        }
+        ResizeArgs {
+            window_size,
+            draw_size,
+        }
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the clamped resize arguments when the window has settled.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<ResizeArgs> {
+        if let Some(args) = e.resize_args() {
+            self.pending = Some((args, 0.0));
+        }
+        let args = e.update_args()?;
+        let (resize, elapsed) = self.pending.as_mut()?;
+        *elapsed += args.dt;
+        if *elapsed < self.debounce {
+            return None;
+        }
+        let resize = *resize;
+        self.pending = None;
+        Some(self.clamp(&resize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Event, Input, UpdateArgs};
+
+    fn resize(w: f64, h: f64) -> Event {
+        Input::Resize(ResizeArgs {
+            window_size: [w, h],
+            draw_size: [w as u32 * 2, h as u32 * 2],
+        })
+        .into()
+    }
+
+    #[test]
+    fn test_clamped_settled_resize() {
+        let mut controller = ResizeController::new([100.0, 100.0], [800.0, 600.0], 0.25);
+        let update: Event = UpdateArgs { dt: 0.1 }.into();
+        for &(w, h) in &[(50.0, 50.0), (900.0, 300.0), (1000.0, 700.0)] {
+            assert_eq!(controller.event(&resize(w, h)), None);
+            assert_eq!(controller.event(&update), None);
+        }
+        assert_eq!(controller.event(&update), None);
+        assert_eq!(
+            controller.event(&update),
+            Some(ResizeArgs {
+                window_size: [800.0, 600.0],
+                draw_size: [1600, 1200],
+            })
+        );
+        assert!(!controller.is_pending());
+        assert_eq!(controller.event(&update), None);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::GenericEvent;
+
+/// The default speed in scroll ticks per second below which scrolling stops.
+const DEFAULT_STOP_SPEED: f64 = 0.01;
+
+/// Momentum scrolling, where the view keeps gliding after scrolling.
+///
+/// Mouse scroll events add velocity, and every update event emits
+/// the residual scroll for that update, decaying exponentially with the friction.
+/// The total residual scroll of one impulse equals the scroll amount of the impulse.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScrollInertia {
+    /// The exponential decay rate of the velocity per second.
+    ///
+    /// Higher values make the scrolling stop sooner. Must be positive.
+    pub friction: f64,
+    /// The speed in scroll ticks per second below which scrolling stops.
+    pub stop_speed: f64,
+    velocity: [f64; 2],
+}
+
+impl ScrollInertia {
+    /// Creates a new momentum scroll helper with friction.
+    pub fn new(friction: f64) -> ScrollInertia {
+        ScrollInertia {
+            friction,
+            stop_speed: DEFAULT_STOP_SPEED,
+            velocity: [0.0; 2],
+        }
+    }
+
+    /// Returns the current scroll velocity in scroll ticks per second.
+    pub fn velocity(&self) -> [f64; 2] {
+        self.velocity
+    }
+
+    /// Stops scrolling immediately.
+    pub fn stop(&mut self) {
+        self.velocity = [0.0; 2];
+    }
+
+    /// Handles an event.
+    ///
+    /// Returns the residual scroll when receiving an update event while gliding.
+    pub fn event<E: GenericEvent>(&mut self, e: &E) -> Option<[f64; 2]> {
+        if let Some(pos) = e.mouse_scroll_args() {
+            self.velocity[0] += pos[0] * self.friction;
+            self.velocity[1] += pos[1] * self.friction;
+        }
+        if let Some(args) = e.update_args() {
+            if self.velocity == [0.0; 2] {
+                return None;
+            }
+            let decay = (-self.friction * args.dt).exp();
+            let scroll = [
+                self.velocity[0] * (1.0 - decay) / self.friction,
+                self.velocity[1] * (1.0 - decay) / self.friction,
+            ];
+            self.velocity[0] *= decay;
+            self.velocity[1] *= decay;
+            let speed = self.velocity[0].hypot(self.velocity[1]);
+            if speed < self.stop_speed {
+                self.stop();
+            }
+            return Some(scroll);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Event, Motion, UpdateArgs};
+
+    #[test]
+    fn test_scroll_inertia() {
+        let mut inertia = ScrollInertia::new(4.0);
+        let scroll: Event = Motion::MouseScroll([0.0, 1.0]).into();
+        let update: Event = UpdateArgs { dt: 0.1 }.into();
+        assert_eq!(inertia.event(&scroll), None);
+
+        let mut prev = f64::INFINITY;
+        let mut total = 0.0;
+        for _ in 0..10 {
+            let [x, y] = inertia.event(&update).unwrap();
+            assert_eq!(x, 0.0);
+            assert!(y > 0.0 && y < prev);
+            prev = y;
+            total += y;
+        }
+        assert!(total < 1.0);
+
+        while let Some([_, y]) = inertia.event(&update) {
+            total += y;
+        }
+        assert_eq!(inertia.velocity(), [0.0; 2]);
+        assert!((total - 1.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{Event, Loop};
+
+/// Slow update arguments, such as delta time in seconds.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct SlowUpdateArgs {
+    /// Delta time in seconds.
+    pub dt: f64,
+}
+
+/// When the application state should be updated at a lower rate,
+/// e.g. for AI or path finding, alongside the regular updates.
+pub trait SlowUpdateEvent: Sized {
+    /// Creates a slow update event.
+    fn from_slow_update_args(args: &SlowUpdateArgs, old_event: &Self) -> Option<Self>;
+    /// Calls closure if this is a slow update event.
+    fn slow_update<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnMut(&SlowUpdateArgs) -> U;
+    /// Returns slow update arguments.
+    fn slow_update_args(&self) -> Option<SlowUpdateArgs> {
+        self.slow_update(|args| *args)
+    }
+}
+
+impl SlowUpdateEvent for Event {
+    fn from_slow_update_args(args: &SlowUpdateArgs, _old_event: &Self) -> Option<Self> {
+        Some(Event::Loop(Loop::SlowUpdate(*args)))
+    }
+
+    fn slow_update<U, F>(&self, mut f: F) -> Option<U>
+    where
+        F: FnMut(&SlowUpdateArgs) -> U,
+    {
+        match *self {
+            Event::Loop(Loop::SlowUpdate(ref args)) => Some(f(args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_slow_update() {
+        let e: Event = SlowUpdateArgs { dt: 0.0 }.into();
+        let x: Option<Event> =
+            SlowUpdateEvent::from_slow_update_args(&SlowUpdateArgs { dt: 1.0 }, &e);
+        let y: Option<Event> = x
+            .clone()
+            .unwrap()
+            .slow_update(|args| SlowUpdateEvent::from_slow_update_args(args, x.as_ref().unwrap()))
+            .unwrap();
+        assert_eq!(x, y);
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::BTreeSet;
+
+use crate::{Button, GenericEvent};
+
+/// A press/release anomaly reported by [`StuckKeyDetector`](struct.StuckKeyDetector.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StuckKeyWarning {
+    /// A button was released without being pressed.
+    UnmatchedRelease(Button),
+    /// A button was held down when the window lost focus,
+    /// so its release might never be received.
+    HeldAfterFocusLoss(Button),
+}
+
+/// Watches press and release events for anomalies,
+/// e.g. for debugging reports of stuck keys.
+///
+/// Buttons held down when the window loses focus are reported once,
+/// so their first release afterwards is not reported again.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StuckKeyDetector {
+    held: BTreeSet<Button>,
+    /// Buttons held down when the window lost focus.
+    lost: BTreeSet<Button>,
+}
+
+impl StuckKeyDetector {
+    /// Creates a new stuck key detector without any held buttons.
+    pub fn new() -> StuckKeyDetector {
+        StuckKeyDetector::default()
+    }
+
+    /// Returns `true` if a button is held down.
+    pub fn is_held(&self, button: Button) -> bool {
+        self.held.contains(&button)
+    }
+
+    /// Handles an event.
+    ///
+    /// Calls `f` for every anomaly found, e.g. to log a warning.
+    pub fn event<E, F>(&mut self, e: &E, mut f: F)
+    where
+        E: GenericEvent,
+        F: FnMut(StuckKeyWarning),
+    {
+        if let Some(button) = e.press_args() {
+            self.lost.remove(&button);
+            self.held.insert(button);
+        }
+        if let Some(button) = e.release_args() {
+            if !self.held.remove(&button) && !self.lost.remove(&button) {
+                f(StuckKeyWarning::UnmatchedRelease(button));
+            }
+        }
+        if let Some(false) = e.focus_args() {
+            for button in std::mem::take(&mut self.held) {
+                f(StuckKeyWarning::HeldAfterFocusLoss(button));
+                self.lost.insert(button);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ButtonArgs, ButtonState, Event, Input, Key};
+
+    #[test]
+    fn test_stuck_key_warnings() {
+        let mut detector = StuckKeyDetector::new();
+        let mut warnings = vec![];
+        detector.event(
+            &Event::from(ButtonArgs::new(ButtonState::Release, Key::A.into())),
+            |w| warnings.push(w),
+        );
+        assert_eq!(
+            warnings,
+            vec![StuckKeyWarning::UnmatchedRelease(Key::A.into())]
+        );
+
+        warnings.clear();
+        detector.event(
+            &Event::from(ButtonArgs::new(ButtonState::Press, Key::B.into())),
+            |w| warnings.push(w),
+        );
+        assert!(detector.is_held(Key::B.into()));
+        detector.event(&Event::from(Input::Focus(false)), |w| warnings.push(w));
+        assert_eq!(
+            warnings,
+            vec![StuckKeyWarning::HeldAfterFocusLoss(Key::B.into())]
+        );
+        assert!(!detector.is_held(Key::B.into()));
+
+        // The release after losing focus is not reported again.
+        warnings.clear();
+        let release = Event::from(ButtonArgs::new(ButtonState::Release, Key::B.into()));
+        detector.event(&release, |w| warnings.push(w));
+        assert_eq!(warnings, vec![]);
+        detector.event(&release, |w| warnings.push(w));
+        assert_eq!(
+            warnings,
+            vec![StuckKeyWarning::UnmatchedRelease(Key::B.into())]
+        );
+    }
+}
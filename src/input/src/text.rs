@@ -3,11 +3,25 @@ use std::borrow::ToOwned;
 use crate::{Event, Input};
 
 /// When receiving text from user, such as typing a character.
+///
+/// Window back-ends only emit text events while text input is enabled,
+/// see `AdvancedWindow::set_text_input` in the window crate.
+///
+/// The text is always valid UTF-8.
+/// Back-ends that receive raw bytes should use [`from_utf8`](#method.from_utf8),
+/// which rejects malformed text at the boundary.
 pub trait TextEvent: Sized {
     /// Creates a text event.
     ///
     /// Preserves time stamp from original input event, if any.
     fn from_text(text: &str, old_event: &Self) -> Option<Self>;
+    /// Creates a text event from raw bytes.
+    ///
+    /// Returns `None` if the bytes are not valid UTF-8.
+    /// Preserves time stamp from original input event, if any.
+    fn from_utf8(bytes: &[u8], old_event: &Self) -> Option<Self> {
+        Self::from_text(std::str::from_utf8(bytes).ok()?, old_event)
+    }
     /// Calls closure if this is a text event.
     fn text<U, F>(&self, f: F) -> Option<U>
     where
@@ -56,4 +70,13 @@ mod tests {
             .unwrap();
         assert_eq!(x, y);
     }
+
+    #[test]
+    fn test_input_text_utf8() {
+        let e: Event = Input::Text("".to_string()).into();
+        let x: Event = TextEvent::from_utf8("hei på deg ✓".as_bytes(), &e).unwrap();
+        assert_eq!(x.text_args(), Some("hei på deg ✓".to_string()));
+        let y: Option<Event> = TextEvent::from_utf8(&[b'a', 0xff, 0xfe], &e);
+        assert_eq!(y, None);
+    }
 }
@@ -11,22 +11,26 @@ fn test_encode_decode() {
         assert_eq!(decoded, input);
     };
 
-    test(Input::Button(ButtonArgs {
-        state: ButtonState::Press,
-        button: Button::Keyboard(Key::A),
-        scancode: None,
-    }));
-    test(Input::Button(ButtonArgs {
-        state: ButtonState::Release,
-        button: Button::Keyboard(Key::A),
-        scancode: None,
-    }));
+    test(Input::Button(ButtonArgs::new(
+        ButtonState::Press,
+        Button::Keyboard(Key::A),
+    )));
+    test(Input::Button(ButtonArgs::new(
+        ButtonState::Release,
+        Button::Keyboard(Key::A),
+    )));
     test(Input::Move(Motion::MouseCursor([0.0, 0.0])));
+    test(Input::Move(Motion::MouseScroll([0.0, 1.0])));
+    test(Input::Move(Motion::MouseScrollPixels([0.0, 1.0])));
     test(Input::Text("hello".into()));
     test(Input::Resize(ResizeArgs {
         window_size: [0.0, 0.0],
         draw_size: [0, 0],
     }));
+    test(Input::ScaleFactorChanged(ScaleFactorChangedArgs {
+        scale_factor: 2.0,
+        new_draw_size: [200, 100],
+    }));
     test(Input::Focus(true));
     test(Input::Cursor(true));
     test(Input::Close(CloseArgs));
@@ -44,4 +48,59 @@ fn test_encode_decode() {
     test(Loop::AfterRender(AfterRenderArgs));
     test(Loop::Update(UpdateArgs { dt: 0.0 }));
     test(Loop::Idle(IdleArgs { dt: 0.0 }));
+    test(Loop::SlowUpdate(SlowUpdateArgs { dt: 0.1 }));
+}
+
+#[test]
+fn test_encode_decode_key_map() {
+    let mut map = KeyMap::new();
+    map.bind(Button::Keyboard(Key::Space), "jump".to_string());
+    map.bind(Button::Mouse(MouseButton::Left), "shoot".to_string());
+
+    let encoded = serde_json::to_string(&map).unwrap();
+    let decoded: KeyMap<String> = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(decoded, map);
+
+    // Bindings are saved in the same order regardless of insertion order.
+    let mut reversed = KeyMap::new();
+    reversed.bind(Button::Mouse(MouseButton::Left), "shoot".to_string());
+    reversed.bind(Button::Keyboard(Key::Space), "jump".to_string());
+    assert_eq!(serde_json::to_string(&reversed).unwrap(), encoded);
+}
+
+#[test]
+fn test_encode_decode_input_snapshot() {
+    let mut snapshot = InputSnapshot::new();
+    snapshot.event(&Event::from(ButtonArgs::new(
+        ButtonState::Press,
+        Key::W.into(),
+    )));
+    snapshot.event(&Event::from(ButtonArgs::new(
+        ButtonState::Press,
+        Key::LShift.into(),
+    )));
+    snapshot.event(&Event::from(Motion::MouseCursor([12.0, 34.0])));
+    assert_eq!(snapshot.pressed_count(), 2);
+
+    let encoded = serde_json::to_string(&snapshot).unwrap();
+    let decoded: InputSnapshot = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(decoded, snapshot);
+
+    // Buttons in any order are sorted when decoding.
+    let mut value = serde_json::to_value(&snapshot).unwrap();
+    value["held"].as_array_mut().unwrap().reverse();
+    let decoded: InputSnapshot = serde_json::from_value(value).unwrap();
+    assert!(decoded.is_held(Key::W.into()));
+    assert_eq!(decoded, snapshot);
+}
+
+#[test]
+fn test_encode_decode_focus_args() {
+    let focus = FocusArgs {
+        keyboard: false,
+        pointer: true,
+    };
+    let encoded = serde_json::to_string(&focus).unwrap();
+    let decoded: FocusArgs = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(decoded, focus);
 }
@@ -32,6 +32,9 @@
 //! The [`Size`](./struct.Size.html) structure is used throughout Piston to store window sizes.
 //! It implements some conversion traits for convenience.
 
+#[macro_use]
+extern crate serde_derive;
+
 use std::{convert::From, error::Error, time::Duration};
 
 pub use graphics_api_version::{UnsupportedGraphicsApiError, Version as Api};
@@ -55,7 +58,7 @@ pub type ProcAddress = *const ();
 ///
 /// Normally, the consideration of points vs pixels should be left to the
 /// host operating system.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Size {
     /// The width.
     pub width: f64,
@@ -181,6 +184,43 @@ impl From<Position> for (i32, i32) {
     }
 }
 
+/// Models the fullscreen mode of a window.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Fullscreen {
+    /// A normal window.
+    Windowed,
+    /// A window without borders covering the whole screen.
+    Borderless,
+    /// Exclusive fullscreen on a monitor, identified by the monitor index.
+    Exclusive(u32),
+}
+
+/// The kind of user attention requested by a window.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttentionKind {
+    /// Requests attention until the user focuses the window,
+    /// e.g. by flashing the taskbar icon repeatedly.
+    Critical,
+    /// Requests attention briefly,
+    /// e.g. by flashing the taskbar icon once.
+    Informational,
+    /// Cancels a previous request for attention.
+    Cancel,
+}
+
+/// Stores information about a monitor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorInfo {
+    /// The name of the monitor.
+    pub name: String,
+    /// The resolution of the monitor in pixels.
+    pub size: [u32; 2],
+    /// The position of the monitor on the virtual desktop in pixels.
+    pub position: [i32; 2],
+    /// The number of pixels per point.
+    pub scale_factor: f64,
+}
+
 /// Constructs a window from a [`WindowSettings`](./struct.WindowSettings.html)
 /// object.
 ///
@@ -214,6 +254,21 @@ pub trait Window {
     /// Returns true if the window should close.
     fn should_close(&self) -> bool;
 
+    /// Requests the window to close, e.g. from a "Quit" menu item.
+    ///
+    /// This is the same as `set_should_close(true)`,
+    /// and can be cancelled with `set_should_close(false)`.
+    fn close(&mut self) {
+        self.set_should_close(true);
+    }
+
+    /// Moves the mouse cursor to a position in points relative to the window,
+    /// e.g. to center the cursor at startup.
+    ///
+    /// By default, this does nothing,
+    /// for window back-ends that can not move the cursor.
+    fn set_cursor_position(&mut self, _pos: [f64; 2]) {}
+
     /// Gets the size of the window.
     fn size(&self) -> Size;
 
@@ -246,6 +301,58 @@ pub trait Window {
     /// This information is given to the client code through the
     /// [`Render`](../input/enum.Event.html) event.
     fn draw_size(&self) -> Size;
+
+    /// Returns true if the window is minimized.
+    ///
+    /// The event loop does not render while the window is minimized.
+    ///
+    /// By default, this returns `false`,
+    /// for window back-ends that can not detect minimized windows.
+    fn is_minimized(&self) -> bool {
+        false
+    }
+
+    /// Returns the ratio between pixels and points of the window,
+    /// e.g. for scaling user interfaces on high DPI monitors.
+    ///
+    /// When the scale factor changes, e.g. when moving the window between monitors,
+    /// the window back-end should emit a `ScaleFactorChanged` input event.
+    ///
+    /// By default, this returns `1.0`,
+    /// for window back-ends that do not support high DPI.
+    fn scale_factor(&self) -> f64 {
+        1.0
+    }
+
+    /// Returns true if the window has input focus.
+    ///
+    /// The event loop uses this to emit the initial focus state.
+    ///
+    /// By default, this returns `true`,
+    /// for window back-ends that can not detect focus.
+    fn is_focused(&self) -> bool {
+        true
+    }
+
+    /// Returns the key repeat settings `(delay, rate)` of the operating system,
+    /// with the delay in seconds and the rate in repeats per second.
+    ///
+    /// This is used to configure `KeyRepeat` in the input crate.
+    ///
+    /// By default, this returns `None`,
+    /// for window back-ends that can not read the settings.
+    fn key_repeat_settings(&self) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// Returns the refresh rate in Hz of the monitor showing the window,
+    /// e.g. to set the maximum frames per second of the event loop.
+    ///
+    /// By default, this returns `None`,
+    /// for window back-ends that can not read the refresh rate.
+    fn refresh_rate(&self) -> Option<f64> {
+        None
+    }
 }
 
 /// Trait representing a window with the most features that are still generic.
@@ -375,6 +482,185 @@ pub trait AdvancedWindow: Window + Sized {
         self.set_position(val);
         self
     }
+
+    /// Gets the fullscreen mode of the window.
+    ///
+    /// By default, this returns `Fullscreen::Windowed`.
+    fn get_fullscreen(&self) -> Fullscreen {
+        Fullscreen::Windowed
+    }
+
+    /// Sets the fullscreen mode of the window.
+    ///
+    /// If the platform does not support this, it will have no effect.
+    fn set_fullscreen(&mut self, _value: Fullscreen) {}
+
+    /// Sets the fullscreen mode of the window.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_fullscreen()`](#method.set_fullscreen), so
+    /// that it can be used in method chaining.
+    fn fullscreen(mut self, value: Fullscreen) -> Self {
+        self.set_fullscreen(value);
+        self
+    }
+
+    /// Gets the available monitors.
+    ///
+    /// The index of a monitor in the list is used by `Fullscreen::Exclusive`.
+    ///
+    /// By default, this returns an empty list,
+    /// for window back-ends that do not support monitor enumeration.
+    fn get_monitors(&self) -> Vec<MonitorInfo> {
+        vec![]
+    }
+
+    /// Starts rumble (force feedback) on a controller.
+    ///
+    /// The controller id is the same as in controller input events.
+    /// The `low` and `high` frequency motor strengths are in the range `0.0..=1.0`.
+    /// A new rumble replaces the current one, and a zero strength stops rumbling.
+    ///
+    /// By default, this does nothing,
+    /// for window back-ends that do not support rumble.
+    fn set_rumble(&mut self, _id: u32, _low: f32, _high: f32, _duration_ms: u32) {}
+
+    /// Requests user attention, e.g. by flashing the taskbar or dock icon
+    /// when a background task finishes.
+    ///
+    /// By default, this does nothing,
+    /// for window back-ends that do not support requesting attention.
+    fn request_attention(&mut self, _kind: AttentionKind) {}
+
+    /// Gets whether text input is enabled.
+    ///
+    /// By default, this returns `true`,
+    /// for window back-ends that always emit text events.
+    fn get_text_input(&self) -> bool {
+        true
+    }
+
+    /// Enables or disables text input.
+    ///
+    /// Text events, including composition by an input method editor (IME),
+    /// are only emitted while text input is enabled.
+    /// Disable text input when no text field has focus,
+    /// to avoid interfering with other controls, e.g. in games.
+    ///
+    /// By default, this does nothing,
+    /// for window back-ends that always emit text events.
+    fn set_text_input(&mut self, _value: bool) {}
+
+    /// Enables or disables text input.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_text_input()`](#method.set_text_input),
+    /// so that it can be used in method chaining.
+    fn text_input(mut self, value: bool) -> Self {
+        self.set_text_input(value);
+        self
+    }
+
+    /// Gets whether the window stays above other windows.
+    ///
+    /// By default, this returns `false`,
+    /// for window back-ends that do not support always on top windows.
+    fn get_always_on_top(&self) -> bool {
+        false
+    }
+
+    /// Sets whether the window stays above other windows,
+    /// e.g. for floating tool palettes.
+    ///
+    /// By default, this does nothing,
+    /// for window back-ends that do not support always on top windows.
+    fn set_always_on_top(&mut self, _value: bool) {}
+
+    /// Sets whether the window stays above other windows.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_always_on_top()`](#method.set_always_on_top),
+    /// so that it can be used in method chaining.
+    fn always_on_top(mut self, value: bool) -> Self {
+        self.set_always_on_top(value);
+        self
+    }
+
+    /// Gets the minimum size of the window, if any.
+    ///
+    /// By default, this returns `None`,
+    /// for window back-ends that do not support size limits.
+    fn get_min_size(&self) -> Option<Size> {
+        None
+    }
+
+    /// Sets the minimum size the user can resize the window to,
+    /// or `None` to remove the limit.
+    ///
+    /// By default, this does nothing,
+    /// for window back-ends that do not support size limits.
+    fn set_min_size(&mut self, _value: Option<Size>) {}
+
+    /// Sets the minimum size the user can resize the window to.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_min_size()`](#method.set_min_size),
+    /// so that it can be used in method chaining.
+    fn min_size(mut self, value: Option<Size>) -> Self {
+        self.set_min_size(value);
+        self
+    }
+
+    /// Gets the maximum size of the window, if any.
+    ///
+    /// By default, this returns `None`,
+    /// for window back-ends that do not support size limits.
+    fn get_max_size(&self) -> Option<Size> {
+        None
+    }
+
+    /// Sets the maximum size the user can resize the window to,
+    /// or `None` to remove the limit.
+    ///
+    /// By default, this does nothing,
+    /// for window back-ends that do not support size limits.
+    fn set_max_size(&mut self, _value: Option<Size>) {}
+
+    /// Sets the maximum size the user can resize the window to.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_max_size()`](#method.set_max_size),
+    /// so that it can be used in method chaining.
+    fn max_size(mut self, value: Option<Size>) -> Self {
+        self.set_max_size(value);
+        self
+    }
+
+    /// Gets the opacity of the window, from `0.0` (transparent) to `1.0` (opaque).
+    ///
+    /// By default, this returns `1.0`,
+    /// for window back-ends that do not support opacity.
+    fn get_opacity(&self) -> f32 {
+        1.0
+    }
+
+    /// Sets the opacity of the window, e.g. to fade an overlay window in or out.
+    ///
+    /// Values outside `0.0` to `1.0` are clamped.
+    ///
+    /// By default, this does nothing,
+    /// for window back-ends that do not support opacity.
+    fn set_opacity(&mut self, _value: f32) {}
+
+    /// Sets the opacity of the window.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_opacity()`](#method.set_opacity),
+    /// so that it can be used in method chaining.
+    fn opacity(mut self, value: f32) -> Self {
+        self.set_opacity(value);
+        self
+    }
 }
 
 /// Trait for OpenGL specific operations on a window.
@@ -409,7 +695,13 @@ pub trait OpenGLWindow: Window {
 /// constructing most windows. This structure makes it easy to create multiple
 /// windows with the same settings, and it also makes piston's multiple backends
 /// easier to implement for piston devs.
-#[derive(Clone, Debug)]
+///
+/// Window settings can be serialized, e.g. to save user preferences.
+/// The graphics API is not serialized, and is `None` after deserializing.
+/// Fields missing when deserializing, e.g. from settings saved by an older version,
+/// use the defaults of `WindowSettings::default()`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
 pub struct WindowSettings {
     title: String,
     size: Size,
@@ -418,12 +710,24 @@ pub struct WindowSettings {
     exit_on_esc: bool,
     automatic_close: bool,
     vsync: bool,
+    #[serde(skip)]
     graphics_api: Option<Api>,
     srgb: bool,
     resizable: bool,
     decorated: bool,
     controllers: bool,
     transparent: bool,
+    always_on_top: bool,
+    lock_aspect_ratio: Option<f64>,
+}
+
+impl Default for WindowSettings {
+    /// Creates window settings with an empty title and a size of 640x480.
+    ///
+    /// Other fields use the defaults of [`new()`](#method.new).
+    fn default() -> WindowSettings {
+        WindowSettings::new("", [640, 480])
+    }
 }
 
 impl WindowSettings {
@@ -437,6 +741,9 @@ impl WindowSettings {
     /// - resizable: true
     /// - decorated: true
     /// - controllers: true
+    /// - transparent: false
+    /// - `always_on_top`: false
+    /// - `lock_aspect_ratio`: None
     pub fn new<T: Into<String>, S: Into<Size>>(title: T, size: S) -> WindowSettings {
         WindowSettings {
             title: title.into(),
@@ -452,6 +759,8 @@ impl WindowSettings {
             decorated: true,
             controllers: true,
             transparent: false,
+            always_on_top: false,
+            lock_aspect_ratio: None,
         }
     }
 
@@ -804,17 +1113,28 @@ impl WindowSettings {
     }
 
     /// Gets whether built windows should be transparent.
+    ///
+    /// A transparent window has a frame buffer with an alpha channel,
+    /// which is composited with the content behind the window,
+    /// e.g. for overlays.
+    /// Window back-ends choose a pixel format with alpha when this is enabled.
     pub fn get_transparent(&self) -> bool {
         self.transparent
     }
 
     /// Sets whether built windows should be transparent.
+    ///
+    /// See [`get_transparent()`](#method.get_transparent) for more information about
+    /// the transparent setting.
     pub fn set_transparent(&mut self, value: bool) {
         self.transparent = value;
     }
 
     /// Sets whether built windows should be transparent.
     ///
+    /// See [`get_transparent()`](#method.get_transparent) for more information about
+    /// the transparent setting.
+    ///
     /// This method moves the current window data,
     /// unlike [`set_transparent()`](#method.set_transparent),
     /// so that it can be used in method chaining.
@@ -822,4 +1142,98 @@ impl WindowSettings {
         self.set_transparent(value);
         self
     }
+
+    /// Gets whether built windows should stay above other windows.
+    pub fn get_always_on_top(&self) -> bool {
+        self.always_on_top
+    }
+
+    /// Sets whether built windows should stay above other windows.
+    pub fn set_always_on_top(&mut self, value: bool) {
+        self.always_on_top = value;
+    }
+
+    /// Sets whether built windows should stay above other windows.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_always_on_top()`](#method.set_always_on_top),
+    /// so that it can be used in method chaining.
+    pub fn always_on_top(mut self, value: bool) -> Self {
+        self.set_always_on_top(value);
+        self
+    }
+
+    /// Gets the aspect ratio (width / height) to keep when resizing built windows.
+    pub fn get_lock_aspect_ratio(&self) -> Option<f64> {
+        self.lock_aspect_ratio
+    }
+
+    /// Sets the aspect ratio (width / height) to keep when resizing built windows.
+    ///
+    /// Window back-ends that can not constrain the window size
+    /// should render to a letterboxed viewport instead,
+    /// see `ResizeArgs::letterbox_viewport` in the input crate.
+    pub fn set_lock_aspect_ratio(&mut self, value: Option<f64>) {
+        self.lock_aspect_ratio = value;
+    }
+
+    /// Sets the aspect ratio (width / height) to keep when resizing built windows.
+    ///
+    /// This method moves the current window data,
+    /// unlike [`set_lock_aspect_ratio()`](#method.set_lock_aspect_ratio),
+    /// so that it can be used in method chaining.
+    pub fn lock_aspect_ratio(mut self, value: Option<f64>) -> Self {
+        self.set_lock_aspect_ratio(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transparent() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        assert!(!settings.get_transparent());
+        let mut settings = settings.transparent(true);
+        assert!(settings.get_transparent());
+        settings.set_transparent(false);
+        assert!(!settings.get_transparent());
+    }
+
+    #[test]
+    fn test_encode_decode_window_settings() {
+        let settings = WindowSettings::new("test", [640, 480])
+            .fullscreen(true)
+            .samples(4)
+            .vsync(true)
+            .always_on_top(true)
+            .lock_aspect_ratio(Some(16.0 / 9.0));
+        let encoded = serde_json::to_string(&settings).unwrap();
+        let decoded: WindowSettings = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded, settings);
+
+        // The graphics API is skipped.
+        let settings = settings.graphics_api(Api::opengl(3, 2));
+        let encoded = serde_json::to_string(&settings).unwrap();
+        let decoded: WindowSettings = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded.get_maybe_graphics_api(), None);
+    }
+
+    #[test]
+    fn test_decode_window_settings_missing_fields() {
+        let decoded: WindowSettings =
+            serde_json::from_str(r#"{"title":"test","size":{"width":800.0,"height":600.0}}"#)
+                .unwrap();
+        assert_eq!(decoded, WindowSettings::new("test", [800, 600]));
+    }
+
+    #[test]
+    fn test_always_on_top() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        assert!(!settings.get_always_on_top());
+        let settings = settings.always_on_top(true);
+        assert!(settings.get_always_on_top());
+    }
 }
@@ -9,7 +9,9 @@ use std::{error::Error, time::Duration};
 
 use input::Event;
 
-use crate::{AdvancedWindow, BuildFromWindowSettings, Position, Size, Window, WindowSettings};
+use crate::{
+    AdvancedWindow, BuildFromWindowSettings, Fullscreen, Position, Size, Window, WindowSettings,
+};
 
 /// A window without user interface, often used in server event loops.
 ///
@@ -24,6 +26,14 @@ pub struct NoWindow {
     title: String,
     size: Size,
     pos: Position,
+    fullscreen: Fullscreen,
+    text_input: bool,
+    always_on_top: bool,
+    min_size: Option<Size>,
+    max_size: Option<Size>,
+    opacity: f32,
+    polls_left: Option<u32>,
+    cursor_pos: [f64; 2],
 }
 
 impl NoWindow {
@@ -35,6 +45,45 @@ impl NoWindow {
             title: settings.get_title(),
             size: settings.get_size(),
             pos: Position { x: 0, y: 0 },
+            fullscreen: if settings.get_fullscreen() {
+                Fullscreen::Borderless
+            } else {
+                Fullscreen::Windowed
+            },
+            text_input: true,
+            always_on_top: settings.get_always_on_top(),
+            min_size: None,
+            max_size: None,
+            opacity: 1.0,
+            polls_left: None,
+            cursor_pos: [0.0; 2],
+        }
+    }
+
+    /// Closes the window after it has been polled for events a number of times,
+    /// e.g. to simulate the user quitting in tests.
+    pub fn set_close_after(&mut self, polls: u32) {
+        self.polls_left = Some(polls);
+    }
+
+    /// Closes the window after it has been polled for events a number of times.
+    ///
+    /// This method moves the current window,
+    /// unlike [`set_close_after()`](#method.set_close_after),
+    /// so that it can be used in method chaining.
+    pub fn close_after(mut self, polls: u32) -> Self {
+        self.set_close_after(polls);
+        self
+    }
+
+    /// Returns the cursor position set by `Window::set_cursor_position`.
+    pub fn get_cursor_position(&self) -> [f64; 2] {
+        self.cursor_pos
+    }
+
+    fn count_poll(&mut self) {
+        if let Some(n) = self.polls_left.as_mut() {
+            *n = n.saturating_sub(1);
         }
     }
 }
@@ -42,10 +91,11 @@ impl NoWindow {
 impl Window for NoWindow {
     fn set_should_close(&mut self, value: bool) {
         self.should_close = value;
+        self.polls_left = None;
     }
 
     fn should_close(&self) -> bool {
-        self.should_close
+        self.should_close || self.polls_left == Some(0)
     }
 
     fn size(&self) -> Size {
@@ -59,16 +109,22 @@ impl Window for NoWindow {
     }
 
     fn wait_event_timeout(&mut self, _timeout: Duration) -> Option<Event> {
+        self.count_poll();
         None
     }
 
     fn poll_event(&mut self) -> Option<Event> {
+        self.count_poll();
         None
     }
 
     fn draw_size(&self) -> Size {
         self.size()
     }
+
+    fn set_cursor_position(&mut self, pos: [f64; 2]) {
+        self.cursor_pos = pos;
+    }
 }
 
 impl BuildFromWindowSettings for NoWindow {
@@ -120,4 +176,203 @@ impl AdvancedWindow for NoWindow {
     fn set_size<S: Into<Size>>(&mut self, val: S) {
         self.size = val.into();
     }
+
+    fn get_fullscreen(&self) -> Fullscreen {
+        self.fullscreen
+    }
+
+    fn set_fullscreen(&mut self, value: Fullscreen) {
+        self.fullscreen = value;
+    }
+
+    fn get_text_input(&self) -> bool {
+        self.text_input
+    }
+
+    fn set_text_input(&mut self, value: bool) {
+        self.text_input = value;
+    }
+
+    fn get_always_on_top(&self) -> bool {
+        self.always_on_top
+    }
+
+    fn set_always_on_top(&mut self, value: bool) {
+        self.always_on_top = value;
+    }
+
+    fn get_min_size(&self) -> Option<Size> {
+        self.min_size
+    }
+
+    fn set_min_size(&mut self, value: Option<Size>) {
+        self.min_size = value;
+    }
+
+    fn get_max_size(&self) -> Option<Size> {
+        self.max_size
+    }
+
+    fn set_max_size(&mut self, value: Option<Size>) {
+        self.max_size = value;
+    }
+
+    fn get_opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    fn set_opacity(&mut self, value: f32) {
+        self.opacity = value.clamp(0.0, 1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_close() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings);
+        assert!(!window.should_close());
+        window.close();
+        assert!(window.should_close());
+        window.set_should_close(false);
+        assert!(!window.should_close());
+    }
+
+    #[test]
+    fn test_fullscreen() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings);
+        assert_eq!(window.get_fullscreen(), Fullscreen::Windowed);
+        window.set_fullscreen(Fullscreen::Exclusive(1));
+        assert_eq!(window.get_fullscreen(), Fullscreen::Exclusive(1));
+        let window = window.fullscreen(Fullscreen::Borderless);
+        assert_eq!(window.get_fullscreen(), Fullscreen::Borderless);
+    }
+
+    #[test]
+    fn test_monitors() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let window = NoWindow::new(&settings);
+        assert!(window.get_monitors().is_empty());
+    }
+    #[test]
+    fn test_scale_factor() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let window = NoWindow::new(&settings);
+        assert_eq!(window.scale_factor(), 1.0);
+    }
+
+    #[test]
+    fn test_focused() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let window = NoWindow::new(&settings);
+        assert!(window.is_focused());
+    }
+
+    #[test]
+    fn test_key_repeat_settings() {
+        use input::KeyRepeat;
+
+        let settings = WindowSettings::new("test", [640, 480]);
+        let window = NoWindow::new(&settings);
+        assert_eq!(window.key_repeat_settings(), None);
+        let repeat = KeyRepeat::from_settings(window.key_repeat_settings());
+        assert_eq!(repeat.delay, KeyRepeat::DEFAULT_DELAY);
+        assert_eq!(repeat.rate, KeyRepeat::DEFAULT_RATE);
+    }
+
+    #[test]
+    fn test_cursor_position() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings);
+        assert_eq!(window.get_cursor_position(), [0.0, 0.0]);
+        window.set_cursor_position([320.0, 240.0]);
+        assert_eq!(window.get_cursor_position(), [320.0, 240.0]);
+    }
+
+    #[test]
+    fn test_refresh_rate() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let window = NoWindow::new(&settings);
+        assert_eq!(window.refresh_rate(), None);
+    }
+
+    #[test]
+    fn test_request_attention() {
+        use crate::AttentionKind;
+
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings);
+        window.request_attention(AttentionKind::Critical);
+        window.request_attention(AttentionKind::Informational);
+        window.request_attention(AttentionKind::Cancel);
+    }
+
+    #[test]
+    fn test_rumble() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings);
+        window.set_rumble(0, 0.5, 1.0, 200);
+        window.set_rumble(0, 0.0, 0.0, 0);
+    }
+
+    #[test]
+    fn test_always_on_top() {
+        let settings = WindowSettings::new("test", [640, 480]).always_on_top(true);
+        let mut window = NoWindow::new(&settings);
+        assert!(window.get_always_on_top());
+        window.set_always_on_top(false);
+        assert!(!window.get_always_on_top());
+    }
+
+    #[test]
+    fn test_min_max_size() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings);
+        assert_eq!(window.get_min_size(), None);
+        window.set_min_size(Some([320, 240].into()));
+        assert_eq!(window.get_min_size(), Some([320, 240].into()));
+        let window = window.max_size(Some([1280, 960].into())).min_size(None);
+        assert_eq!(window.get_min_size(), None);
+        assert_eq!(window.get_max_size(), Some([1280, 960].into()));
+    }
+
+    #[test]
+    fn test_opacity() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings);
+        assert_eq!(window.get_opacity(), 1.0);
+        window.set_opacity(0.5);
+        assert_eq!(window.get_opacity(), 0.5);
+        window.set_opacity(2.0);
+        assert_eq!(window.get_opacity(), 1.0);
+        let window = window.opacity(-1.0);
+        assert_eq!(window.get_opacity(), 0.0);
+    }
+
+    #[test]
+    fn test_close_after() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings).close_after(3);
+        let mut iterations = 0;
+        while !window.should_close() {
+            assert_eq!(window.poll_event(), None);
+            iterations += 1;
+        }
+        assert_eq!(iterations, 3);
+    }
+
+    #[test]
+    fn test_text_input() {
+        let settings = WindowSettings::new("test", [640, 480]);
+        let mut window = NoWindow::new(&settings);
+        assert!(window.get_text_input());
+        window.set_text_input(false);
+        assert!(!window.get_text_input());
+        let window = window.text_input(true);
+        assert!(window.get_text_input());
+    }
 }